@@ -0,0 +1,291 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::game::{
+    GameState, GameTilemap,
+    entities::{
+        enemy::{
+            Enemy,
+            health::EnemyHealth,
+            status_effect::{EnemyStatusEffect, EnemyStatusEffectVariant, EnemyStatusEffects},
+        },
+        soldier::{
+            Soldier, SoldierVariant, projectile::DamageType, projectile_blast::ProjectileBlast,
+        },
+        tile::{movement::TileMovement, position::TilePosition},
+        tilemap::tile::TilemapTileVariant,
+    },
+    speed::GameSpeed,
+};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum PlayerAbilityEffect {
+    Airstrike {
+        radius: f32,
+        damage: u32,
+        damage_type: DamageType,
+    },
+    Freeze {
+        status_effect: EnemyStatusEffect,
+    },
+    Reinforcements {
+        soldier_variant: SoldierVariant,
+        duration_secs: f32,
+    },
+}
+
+pub struct PlayerAbilityConfig {
+    cooldown_secs: f32,
+    effect: PlayerAbilityEffect,
+}
+
+impl PlayerAbilityConfig {
+    pub fn get_cooldown(&self) -> Duration {
+        Duration::from_secs_f32(self.cooldown_secs)
+    }
+    pub fn get_effect(&self) -> PlayerAbilityEffect {
+        self.effect
+    }
+}
+
+pub const AIRSTRIKE_CONFIG: PlayerAbilityConfig = PlayerAbilityConfig {
+    cooldown_secs: 45.0,
+    effect: PlayerAbilityEffect::Airstrike {
+        radius: 2.0,
+        damage: 150,
+        damage_type: DamageType::Explosive,
+    },
+};
+
+pub const FREEZE_CONFIG: PlayerAbilityConfig = PlayerAbilityConfig {
+    cooldown_secs: 60.0,
+    effect: PlayerAbilityEffect::Freeze {
+        status_effect: EnemyStatusEffect::new(
+            EnemyStatusEffectVariant::Slow {
+                speed_multiplier: 0.0,
+            },
+            3.0,
+        ),
+    },
+};
+
+pub const REINFORCEMENTS_CONFIG: PlayerAbilityConfig = PlayerAbilityConfig {
+    cooldown_secs: 90.0,
+    effect: PlayerAbilityEffect::Reinforcements {
+        soldier_variant: SoldierVariant::Soldier { level: 2 },
+        duration_secs: 20.0,
+    },
+};
+
+#[derive(Clone, Copy, PartialEq, Deserialize)]
+pub enum PlayerAbilityVariant {
+    Airstrike,
+    Freeze,
+    Reinforcements,
+}
+
+impl PlayerAbilityVariant {
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            PlayerAbilityVariant::Airstrike => "ui.in_game.ability.airstrike",
+            PlayerAbilityVariant::Freeze => "ui.in_game.ability.freeze",
+            PlayerAbilityVariant::Reinforcements => "ui.in_game.ability.reinforcements",
+        }
+    }
+    pub fn get_config(&self) -> &'static PlayerAbilityConfig {
+        match self {
+            PlayerAbilityVariant::Airstrike => &AIRSTRIKE_CONFIG,
+            PlayerAbilityVariant::Freeze => &FREEZE_CONFIG,
+            PlayerAbilityVariant::Reinforcements => &REINFORCEMENTS_CONFIG,
+        }
+    }
+    pub fn requires_target(&self) -> bool {
+        match self.get_config().get_effect() {
+            PlayerAbilityEffect::Freeze { .. } => false,
+            _ => true,
+        }
+    }
+    pub fn can_target(&self, tile_variant: TilemapTileVariant, soldier_found: bool) -> bool {
+        match self.get_config().get_effect() {
+            PlayerAbilityEffect::Reinforcements {
+                soldier_variant, ..
+            } => soldier_found == false && soldier_variant.can_be_placed_on(tile_variant),
+            _ => tile_variant != TilemapTileVariant::Unknown,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct PlayerAbility {
+    variant: PlayerAbilityVariant,
+    cooldown: Duration,
+}
+
+impl PlayerAbility {
+    pub fn get_variant(&self) -> PlayerAbilityVariant {
+        self.variant
+    }
+    pub fn get_cooldown(&self) -> Duration {
+        self.cooldown
+    }
+    pub fn is_ready(&self) -> bool {
+        self.cooldown == Duration::ZERO
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct PlayerAbilities {
+    abilities: Vec<PlayerAbility>,
+}
+
+impl PlayerAbilities {
+    pub fn restart(&mut self, variants: &[PlayerAbilityVariant]) {
+        self.abilities = variants
+            .iter()
+            .map(|variant| PlayerAbility {
+                variant: *variant,
+                cooldown: Duration::ZERO,
+            })
+            .collect();
+    }
+    pub fn get_abilities(&self) -> &Vec<PlayerAbility> {
+        &self.abilities
+    }
+    pub fn get_ability(&self, variant: PlayerAbilityVariant) -> Option<&PlayerAbility> {
+        self.abilities
+            .iter()
+            .find(|ability| ability.get_variant() == variant)
+    }
+    pub fn is_ready(&self, variant: PlayerAbilityVariant) -> bool {
+        self.get_ability(variant)
+            .is_some_and(|ability| ability.is_ready())
+    }
+    pub fn is_cooling_down(&self) -> bool {
+        self.abilities
+            .iter()
+            .any(|ability| ability.is_ready() == false)
+    }
+    pub fn update_cooldown(&mut self, variant: PlayerAbilityVariant) {
+        if let Some(ability) = self
+            .abilities
+            .iter_mut()
+            .find(|ability| ability.get_variant() == variant)
+        {
+            ability.cooldown = variant.get_config().get_cooldown();
+        }
+    }
+    pub fn decrease_cooldowns(&mut self, delta_time: Duration) {
+        for ability in self.abilities.iter_mut() {
+            ability.cooldown = ability.cooldown.saturating_sub(delta_time);
+        }
+    }
+}
+
+#[derive(Message)]
+pub struct PlayerAbilityActivated {
+    pub variant: PlayerAbilityVariant,
+    pub tile_position: TilePosition,
+}
+
+pub struct PlayerAbilitiesPlugin;
+
+impl Plugin for PlayerAbilitiesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PlayerAbilities>();
+        app.add_message::<PlayerAbilityActivated>();
+
+        app.add_systems(
+            Update,
+            (activate_player_abilities, update_player_abilities_cooldown)
+                .chain()
+                .run_if(in_state(GameState::InGame)),
+        );
+    }
+}
+
+fn activate_player_abilities(
+    mut commands: Commands,
+    game_tilemap: Single<Entity, With<GameTilemap>>,
+    mut enemies: Query<(
+        &Enemy,
+        &mut EnemyHealth,
+        &mut EnemyStatusEffects,
+        &TileMovement,
+        &TilePosition,
+    )>,
+    mut player_abilities: ResMut<PlayerAbilities>,
+    mut player_ability_activated_events: MessageReader<PlayerAbilityActivated>,
+) {
+    for player_ability_activated in player_ability_activated_events.read() {
+        let variant = player_ability_activated.variant;
+        let tile_position = player_ability_activated.tile_position.as_vec2();
+
+        if player_abilities.is_ready(variant) == false {
+            continue;
+        }
+
+        match variant.get_config().get_effect() {
+            PlayerAbilityEffect::Airstrike {
+                radius,
+                damage,
+                damage_type,
+            } => {
+                for (enemy, mut enemy_health, _, enemy_movement, enemy_tile_position) in
+                    enemies.iter_mut()
+                {
+                    if enemy_movement.get_progress() <= 0.0
+                        || enemy_tile_position.as_vec2().distance(tile_position) > radius
+                    {
+                        continue;
+                    }
+                    enemy_health.damage(
+                        enemy
+                            .get_config()
+                            .get_armor()
+                            .resolve_damage(damage, damage_type),
+                    );
+                }
+                commands.entity(game_tilemap.entity()).with_child((
+                    ProjectileBlast::new(radius),
+                    TilePosition::from_vec2(tile_position),
+                ));
+            }
+            PlayerAbilityEffect::Freeze { status_effect } => {
+                for (_, _, mut enemy_status_effects, enemy_movement, _) in enemies.iter_mut() {
+                    if enemy_movement.get_progress() <= 0.0 {
+                        continue;
+                    }
+                    enemy_status_effects.apply(status_effect);
+                }
+            }
+            PlayerAbilityEffect::Reinforcements {
+                soldier_variant,
+                duration_secs,
+            } => {
+                commands.entity(game_tilemap.entity()).with_child((
+                    Soldier::new(soldier_variant)
+                        .with_lifetime(Duration::from_secs_f32(duration_secs)),
+                    TilePosition::from_vec2(tile_position),
+                ));
+            }
+        }
+
+        player_abilities.update_cooldown(variant);
+    }
+}
+
+fn update_player_abilities_cooldown(
+    mut player_abilities: ResMut<PlayerAbilities>,
+    game_speed: Res<GameSpeed>,
+    time: Res<Time>,
+) {
+    if player_abilities.is_cooling_down() == false {
+        return;
+    }
+
+    player_abilities.decrease_cooldowns(Duration::from_secs_f32(
+        time.delta_secs() * game_speed.as_f32(),
+    ));
+}
@@ -31,6 +31,30 @@ pub enum SoldierSpriteVariant {
     RocketLauncherGreen = 7,
     RocketLauncherBlue = 8,
     RocketLauncherYellow = 9,
+
+    FlamethrowerGray = 74,
+    FlamethrowerRed = 75,
+    FlamethrowerYellow = 76,
+
+    SupportGray = 77,
+    SupportRed = 78,
+    SupportYellow = 79,
+
+    MineGray = 80,
+    MineRed = 81,
+    MineYellow = 82,
+
+    SpikesGray = 84,
+    SpikesRed = 85,
+    SpikesYellow = 86,
+
+    GunboatGray = 87,
+    GunboatRed = 88,
+    GunboatYellow = 89,
+
+    TeslaGray = 90,
+    TeslaRed = 91,
+    TeslaYellow = 92,
 }
 
 #[derive(Clone, Copy)]
@@ -84,4 +108,7 @@ pub enum EnemySpriteVariant {
 pub enum UtilSpriteVariant {
     TileIndicator = 72,
     Glow = 73,
+    RankInsignia1 = 93,
+    RankInsignia2 = 94,
+    RankInsignia3 = 95,
 }
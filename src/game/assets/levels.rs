@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use bevy::{
     asset::{AssetLoader, LoadContext, RenderAssetUsages, io::Reader},
     math::VectorSpace,
@@ -9,20 +11,156 @@ use bevy_persistent::prelude::*;
 use serde::{Deserialize, Serialize};
 
 use crate::game::{
+    abilities::PlayerAbilityVariant,
     entities::{
-        enemy::EnemyVariant,
+        enemy::{EnemyVariant, route::find_route},
         tilemap::tile::{TilemapTile, TilemapTileVariant},
     },
     player::PlayerHealth,
 };
 
+const ENDLESS_GROUPS_PER_WAVE: usize = 3;
+const ENDLESS_BOSS_WAVE_INTERVAL: usize = 5;
+const ENDLESS_HEALTH_GROWTH: f32 = 0.15;
+const ENDLESS_COUNT_GROWTH: f32 = 0.2;
+const ENDLESS_REWARD_GROWTH: f32 = 0.1;
+
+pub const EASY_DIFFICULTY_MODIFIERS: LevelDifficultyModifiers = LevelDifficultyModifiers {
+    player_health: 1.5,
+    player_money: 1.5,
+    enemy_health: 0.75,
+    wave_reward: 1.25,
+    sell_price: 1.15,
+};
+
+pub const NORMAL_DIFFICULTY_MODIFIERS: LevelDifficultyModifiers = LevelDifficultyModifiers {
+    player_health: 1.0,
+    player_money: 1.0,
+    enemy_health: 1.0,
+    wave_reward: 1.0,
+    sell_price: 1.0,
+};
+
+pub const HARD_DIFFICULTY_MODIFIERS: LevelDifficultyModifiers = LevelDifficultyModifiers {
+    player_health: 0.5,
+    player_money: 0.75,
+    enemy_health: 1.5,
+    wave_reward: 0.8,
+    sell_price: 0.75,
+};
+
+#[derive(Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum LevelDifficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+    Custom,
+}
+
+impl LevelDifficulty {
+    pub fn get_all() -> [LevelDifficulty; 4] {
+        [
+            LevelDifficulty::Easy,
+            LevelDifficulty::Normal,
+            LevelDifficulty::Hard,
+            LevelDifficulty::Custom,
+        ]
+    }
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            LevelDifficulty::Easy => "level.difficulty.easy",
+            LevelDifficulty::Normal => "level.difficulty.normal",
+            LevelDifficulty::Hard => "level.difficulty.hard",
+            LevelDifficulty::Custom => "level.difficulty.custom",
+        }
+    }
+    pub fn as_index(&self) -> usize {
+        match self {
+            LevelDifficulty::Easy => 0,
+            LevelDifficulty::Normal => 1,
+            LevelDifficulty::Hard => 2,
+            LevelDifficulty::Custom => 3,
+        }
+    }
+    pub fn from_index(index: usize) -> Self {
+        match index {
+            0 => LevelDifficulty::Easy,
+            1 => LevelDifficulty::Normal,
+            2 => LevelDifficulty::Hard,
+            3 => LevelDifficulty::Custom,
+            _ => LevelDifficulty::default(),
+        }
+    }
+    pub fn get_modifiers(
+        &self,
+        custom_modifiers: &LevelDifficultyModifiers,
+    ) -> LevelDifficultyModifiers {
+        match self {
+            LevelDifficulty::Easy => EASY_DIFFICULTY_MODIFIERS,
+            LevelDifficulty::Normal => NORMAL_DIFFICULTY_MODIFIERS,
+            LevelDifficulty::Hard => HARD_DIFFICULTY_MODIFIERS,
+            LevelDifficulty::Custom => *custom_modifiers,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LevelDifficultyModifiers {
+    player_health: f32,
+    player_money: f32,
+    enemy_health: f32,
+    wave_reward: f32,
+    sell_price: f32,
+}
+
+impl Default for LevelDifficultyModifiers {
+    fn default() -> Self {
+        NORMAL_DIFFICULTY_MODIFIERS
+    }
+}
+
+impl LevelDifficultyModifiers {
+    pub fn get_player_health(&self) -> f32 {
+        self.player_health
+    }
+    pub fn set_player_health(&mut self, player_health: f32) {
+        self.player_health = player_health;
+    }
+    pub fn get_player_money(&self) -> f32 {
+        self.player_money
+    }
+    pub fn set_player_money(&mut self, player_money: f32) {
+        self.player_money = player_money;
+    }
+    pub fn get_enemy_health(&self) -> f32 {
+        self.enemy_health
+    }
+    pub fn set_enemy_health(&mut self, enemy_health: f32) {
+        self.enemy_health = enemy_health;
+    }
+    pub fn get_wave_reward(&self) -> f32 {
+        self.wave_reward
+    }
+    pub fn set_wave_reward(&mut self, wave_reward: f32) {
+        self.wave_reward = wave_reward;
+    }
+    pub fn get_sell_price(&self) -> f32 {
+        self.sell_price
+    }
+    pub fn set_sell_price(&mut self, sell_price: f32) {
+        self.sell_price = sell_price;
+    }
+}
+
 #[derive(AssetCollection, Resource)]
 pub struct LevelsAssets {
     #[asset(
         paths(
             "embedded://levels/ring.ron",
             "embedded://levels/zigzag.ron",
-            "embedded://levels/coastal_highway.ron"
+            "embedded://levels/coastal_highway.ron",
+            "embedded://levels/open_field.ron"
         ),
         collection(typed)
     )]
@@ -68,6 +206,8 @@ impl LevelCompletionStars {
 #[derive(Serialize, Deserialize, Clone, Default)]
 pub struct LevelCompletion {
     name: String,
+    #[serde(default)]
+    difficulty: LevelDifficulty,
     stars: LevelCompletionStars,
 }
 
@@ -75,6 +215,9 @@ impl LevelCompletion {
     pub fn get_name(&self) -> &str {
         &self.name
     }
+    pub fn get_difficulty(&self) -> LevelDifficulty {
+        self.difficulty
+    }
     pub fn get_stars(&self) -> &LevelCompletionStars {
         &self.stars
     }
@@ -84,23 +227,92 @@ impl LevelCompletion {
 pub struct CompletedLevels(Vec<LevelCompletion>);
 
 impl CompletedLevels {
-    pub fn add(&mut self, name: &str, stars: LevelCompletionStars) {
-        if let Some(level_completion) = self.get_completion_mut(name) {
+    pub fn add(&mut self, name: &str, difficulty: LevelDifficulty, stars: LevelCompletionStars) {
+        if let Some(level_completion) = self.get_completion_mut(name, difficulty) {
             if stars.as_index() > level_completion.stars.as_index() {
                 level_completion.stars = stars;
             }
         } else {
             self.0.push(LevelCompletion {
                 name: name.into(),
+                difficulty,
                 stars,
             });
         }
     }
-    pub fn get_completion(&self, name: &str) -> Option<&LevelCompletion> {
-        self.0.iter().find(|level| level.get_name() == name)
+    pub fn get_completion(
+        &self,
+        name: &str,
+        difficulty: LevelDifficulty,
+    ) -> Option<&LevelCompletion> {
+        self.0
+            .iter()
+            .find(|level| level.get_name() == name && level.get_difficulty() == difficulty)
+    }
+    fn get_completion_mut(
+        &mut self,
+        name: &str,
+        difficulty: LevelDifficulty,
+    ) -> Option<&mut LevelCompletion> {
+        self.0
+            .iter_mut()
+            .find(|level| level.get_name() == name && level.get_difficulty() == difficulty)
+    }
+    pub fn is_completed(&self, name: &str) -> bool {
+        self.0.iter().any(|level| level.get_name() == name)
+    }
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn reset(&mut self) {
+        self.0.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct EndlessRecord {
+    name: String,
+    #[serde(default)]
+    difficulty: LevelDifficulty,
+    best_wave: usize,
+}
+
+impl EndlessRecord {
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn get_difficulty(&self) -> LevelDifficulty {
+        self.difficulty
+    }
+    pub fn get_best_wave(&self) -> usize {
+        self.best_wave
     }
-    fn get_completion_mut(&mut self, name: &str) -> Option<&mut LevelCompletion> {
-        self.0.iter_mut().find(|level| level.get_name() == name)
+}
+
+#[derive(Resource, Serialize, Deserialize, Default)]
+pub struct EndlessRecords(Vec<EndlessRecord>);
+
+impl EndlessRecords {
+    pub fn add(&mut self, name: &str, difficulty: LevelDifficulty, wave: usize) {
+        if let Some(endless_record) = self
+            .0
+            .iter_mut()
+            .find(|record| record.get_name() == name && record.get_difficulty() == difficulty)
+        {
+            endless_record.best_wave = endless_record.best_wave.max(wave);
+        } else {
+            self.0.push(EndlessRecord {
+                name: name.into(),
+                difficulty,
+                best_wave: wave,
+            });
+        }
+    }
+    pub fn get_best_wave(&self, name: &str, difficulty: LevelDifficulty) -> Option<usize> {
+        self.0
+            .iter()
+            .find(|record| record.get_name() == name && record.get_difficulty() == difficulty)
+            .map(|record| record.get_best_wave())
     }
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
@@ -117,6 +329,9 @@ pub struct Path {
 }
 
 impl Path {
+    pub fn new(color: Vec3, points: Vec<Vec2>) -> Self {
+        Self { color, points }
+    }
     pub fn get_color(&self) -> Color {
         LinearRgba::from_vec3(self.color).into()
     }
@@ -144,10 +359,13 @@ impl Wave {
 pub struct WaveEnemies {
     enemy_variant: EnemyVariant,
     count: u32,
-    duration: f32,
+    duration: Option<f32>,
     spawn_interval: f32,
     spawn_delay: f32,
     path_index: usize,
+    layered: Option<bool>,
+    boss: Option<bool>,
+    health_multiplier: Option<f32>,
 }
 
 impl WaveEnemies {
@@ -157,7 +375,7 @@ impl WaveEnemies {
     pub fn get_count(&self) -> u32 {
         self.count
     }
-    pub fn get_duration(&self) -> f32 {
+    pub fn get_duration(&self) -> Option<f32> {
         self.duration
     }
     pub fn get_spawn_interval(&self) -> f32 {
@@ -169,6 +387,15 @@ impl WaveEnemies {
     pub fn get_path_index(&self) -> usize {
         self.path_index
     }
+    pub fn is_layered(&self) -> bool {
+        self.layered.unwrap_or(false)
+    }
+    pub fn is_boss(&self) -> bool {
+        self.boss.unwrap_or(false)
+    }
+    pub fn get_health_multiplier(&self) -> f32 {
+        self.health_multiplier.unwrap_or(1.0)
+    }
 }
 
 #[derive(Asset, TypePath, Deserialize)]
@@ -179,6 +406,8 @@ pub struct TileSymbols {
     pub road: char,
     pub bridge: char,
     pub water: char,
+    pub spawn: Option<char>,
+    pub exit: Option<char>,
 }
 
 impl Default for TileSymbols {
@@ -190,11 +419,19 @@ impl Default for TileSymbols {
             bridge: '=',
             road: '.',
             water: '~',
+            spawn: None,
+            exit: None,
         }
     }
 }
 
 impl TileSymbols {
+    pub fn get_spawn(&self) -> char {
+        self.spawn.unwrap_or('S')
+    }
+    pub fn get_exit(&self) -> char {
+        self.exit.unwrap_or('E')
+    }
     pub fn get_tile_variant(&self, char: char) -> TilemapTileVariant {
         if char == self.ground {
             TilemapTileVariant::Ground
@@ -220,6 +457,15 @@ pub struct LevelAsset {
     pub player_health: u32,
     pub player_money: u32,
     pub viewport_size: Option<UVec2>,
+    pub line_of_sight: Option<bool>,
+    pub abilities: Option<Vec<PlayerAbilityVariant>>,
+    pub interest_rate: Option<f32>,
+    pub max_interest: Option<u32>,
+    pub early_call_bonus: Option<f32>,
+    pub overlapping_waves: Option<bool>,
+    pub endless: Option<bool>,
+    pub pathfinding: Option<bool>,
+    pub maze: Option<bool>,
     pub map_size: UVec2,
     pub map: Vec<String>,
     pub tile_symbols: Option<TileSymbols>,
@@ -235,6 +481,15 @@ impl Default for LevelAsset {
             player_health: 0,
             player_money: 0,
             viewport_size: None,
+            line_of_sight: None,
+            abilities: None,
+            interest_rate: None,
+            max_interest: None,
+            early_call_bonus: None,
+            overlapping_waves: None,
+            endless: None,
+            pathfinding: None,
+            maze: None,
             map_size: UVec2::default(),
             map: Vec::new(),
             tile_symbols: None,
@@ -261,8 +516,21 @@ pub struct Level {
     player_health: u32,
     player_money: u32,
     viewport_size: Option<UVec2>,
+    line_of_sight: bool,
+    abilities: Vec<PlayerAbilityVariant>,
+    interest_rate: f32,
+    max_interest: Option<u32>,
+    early_call_bonus: Option<f32>,
+    overlapping_waves: bool,
+    endless: bool,
+    difficulty: LevelDifficulty,
+    sell_price_multiplier: f32,
+    pathfinding: bool,
+    maze: bool,
     map_size: UVec2,
     map: Vec<Vec<TilemapTile>>,
+    spawns: Vec<IVec2>,
+    exits: Vec<IVec2>,
     paths: Vec<Path>,
     waves: Vec<Wave>,
     error: Option<String>,
@@ -271,7 +539,7 @@ pub struct Level {
 impl Level {
     pub fn from_asset(level_asset: LevelAsset) -> Self {
         let tile_symbols = level_asset.tile_symbols.unwrap_or_default();
-        let map: Vec<Vec<TilemapTile>> = level_asset
+        let mut map: Vec<Vec<TilemapTile>> = level_asset
             .map
             .iter()
             .map(|row| {
@@ -281,20 +549,75 @@ impl Level {
             })
             .collect();
 
+        let pathfinding =
+            level_asset.pathfinding.unwrap_or(false) || level_asset.maze.unwrap_or(false);
+
+        let mut spawns = Vec::new();
+        let mut exits = Vec::new();
+        if pathfinding == true {
+            for (y, row) in level_asset.map.iter().enumerate() {
+                for (x, char) in row.chars().enumerate() {
+                    if char == tile_symbols.get_spawn() {
+                        spawns.push(IVec2::new(x as i32, y as i32));
+                    } else if char == tile_symbols.get_exit() {
+                        exits.push(IVec2::new(x as i32, y as i32));
+                    }
+                }
+            }
+            for marker in spawns.iter().chain(exits.iter()) {
+                let marker_variant = [IVec2::X, IVec2::NEG_X, IVec2::Y, IVec2::NEG_Y]
+                    .iter()
+                    .filter_map(|offset| {
+                        let position = marker + offset;
+                        map.get(position.y as usize)
+                            .and_then(|row| row.get(position.x as usize))
+                            .map(|tile| tile.get_variant())
+                    })
+                    .find_map(|variant| match variant {
+                        TilemapTileVariant::Road | TilemapTileVariant::Bridge => {
+                            Some(TilemapTileVariant::Road)
+                        }
+                        TilemapTileVariant::Water => Some(TilemapTileVariant::Water),
+                        _ => None,
+                    })
+                    .unwrap_or(TilemapTileVariant::Ground);
+
+                map[marker.y as usize][marker.x as usize] = TilemapTile::new(marker_variant);
+            }
+        }
+
         Self {
             name: level_asset.name,
             player_health: level_asset.player_health,
             player_money: level_asset.player_money,
             viewport_size: level_asset.viewport_size,
+            line_of_sight: level_asset.line_of_sight.unwrap_or(false),
+            abilities: level_asset.abilities.unwrap_or_default(),
+            interest_rate: level_asset.interest_rate.unwrap_or(0.0),
+            max_interest: level_asset.max_interest,
+            early_call_bonus: level_asset.early_call_bonus,
+            overlapping_waves: level_asset
+                .overlapping_waves
+                .unwrap_or(level_asset.early_call_bonus.is_some()),
+            endless: level_asset.endless.unwrap_or(false),
+            difficulty: LevelDifficulty::default(),
+            sell_price_multiplier: 1.0,
+            pathfinding,
+            maze: level_asset.maze.unwrap_or(false),
             map_size: level_asset.map_size,
             map,
+            spawns,
+            exits,
             paths: level_asset.paths.unwrap_or_default(),
             waves: level_asset.waves.unwrap_or_default(),
             error: level_asset.error,
         }
     }
     pub fn from_source(source: &str) -> Self {
-        let level_asset = match ron::from_str::<LevelAsset>(source) {
+        let level_asset = match ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str::<LevelAsset>(source)
+        {
             Ok(asset) => {
                 if asset.map.len() < asset.map_size.y as usize
                     || asset
@@ -313,11 +636,84 @@ impl Level {
             }
         };
 
-        Self::from_asset(level_asset)
+        let level = Self::from_asset(level_asset);
+        if let Some(error) = level.get_route_error() {
+            return Self::from_asset(LevelAsset::error(error));
+        }
+        level
+    }
+    fn get_route_error(&self) -> Option<String> {
+        if self.is_pathfinding() == false || self.error.is_some() {
+            return None;
+        }
+        if self.spawns.is_empty() {
+            return Some("LevelAsset error: pathfinding requires a spawn marker".to_string());
+        }
+        if self.exits.is_empty() {
+            return Some("LevelAsset error: pathfinding requires an exit marker".to_string());
+        }
+
+        for (wave_index, wave) in self.waves.iter().enumerate() {
+            for wave_enemies in wave.get_enemies().iter() {
+                let path_index = wave_enemies.get_path_index();
+                let Some(spawn) = self.spawns.get(path_index) else {
+                    return Some(format!(
+                        "LevelAsset error: wave {} uses path_index {} without a spawn marker",
+                        wave_index + 1,
+                        path_index
+                    ));
+                };
+                if find_route(
+                    self,
+                    *spawn,
+                    &self.exits,
+                    wave_enemies.get_enemy_variant().get_domain(),
+                    &HashSet::new(),
+                )
+                .is_none()
+                {
+                    return Some(format!(
+                        "LevelAsset error: wave {} enemies have no route from spawn {} to an exit",
+                        wave_index + 1,
+                        path_index
+                    ));
+                }
+            }
+        }
+
+        None
+    }
+    pub fn with_difficulty(
+        mut self,
+        difficulty: LevelDifficulty,
+        modifiers: LevelDifficultyModifiers,
+    ) -> Self {
+        let scale = |value: u32, multiplier: f32| (value as f32 * multiplier).round() as u32;
+
+        self.difficulty = difficulty;
+        self.player_health = scale(self.player_health, modifiers.get_player_health()).max(1);
+        self.player_money = scale(self.player_money, modifiers.get_player_money());
+        self.sell_price_multiplier = modifiers.get_sell_price();
+
+        for wave in self.waves.iter_mut() {
+            wave.reward = scale(wave.reward, modifiers.get_wave_reward());
+            for wave_enemies in wave.enemies.iter_mut() {
+                wave_enemies.health_multiplier =
+                    Some(wave_enemies.get_health_multiplier() * modifiers.get_enemy_health());
+            }
+        }
+
+        self
     }
     pub fn get_name(&self) -> &str {
         &self.name
     }
+    pub fn get_difficulty(&self) -> LevelDifficulty {
+        self.difficulty
+    }
+    pub fn get_sell_price(&self, sell_price: u32) -> u32 {
+        (sell_price as f32 * self.sell_price_multiplier).round() as u32
+    }
     pub fn get_player_health(&self) -> u32 {
         self.player_health
     }
@@ -330,6 +726,51 @@ impl Level {
     pub fn get_viewport_size(&self) -> Option<UVec2> {
         self.viewport_size
     }
+    pub fn has_line_of_sight(&self) -> bool {
+        self.line_of_sight
+    }
+    pub fn get_abilities(&self) -> &Vec<PlayerAbilityVariant> {
+        &self.abilities
+    }
+    pub fn get_interest_rate(&self) -> f32 {
+        self.interest_rate
+    }
+    pub fn get_max_interest(&self) -> Option<u32> {
+        self.max_interest
+    }
+    pub fn get_interest(&self, money: u32) -> u32 {
+        let interest = (money as f32 * self.get_interest_rate()).floor() as u32;
+
+        if let Some(max_interest) = self.get_max_interest() {
+            interest.min(max_interest)
+        } else {
+            interest
+        }
+    }
+    pub fn has_early_call_bonus(&self) -> bool {
+        self.early_call_bonus.is_some()
+    }
+    pub fn has_overlapping_waves(&self) -> bool {
+        self.overlapping_waves
+    }
+    pub fn is_endless(&self) -> bool {
+        self.endless
+    }
+    pub fn get_early_call_bonus(&self, remaining_health: u32) -> u32 {
+        (remaining_health as f32 * self.early_call_bonus.unwrap_or(0.0)).floor() as u32
+    }
+    pub fn is_pathfinding(&self) -> bool {
+        self.pathfinding
+    }
+    pub fn is_maze(&self) -> bool {
+        self.maze
+    }
+    pub fn get_spawns(&self) -> &Vec<IVec2> {
+        &self.spawns
+    }
+    pub fn get_exits(&self) -> &Vec<IVec2> {
+        &self.exits
+    }
     pub fn get_map(&self) -> &Vec<Vec<TilemapTile>> {
         &self.map
     }
@@ -352,8 +793,81 @@ impl Level {
     pub fn get_waves(&self) -> &Vec<Wave> {
         &self.waves
     }
-    pub fn get_wave(&self, wave_index: usize) -> Option<&Wave> {
-        self.waves.get(wave_index)
+    pub fn get_wave(&self, wave_index: usize) -> Option<Wave> {
+        if let Some(wave) = self.waves.get(wave_index) {
+            return Some(wave.clone());
+        }
+        if self.is_endless() == false {
+            return None;
+        }
+        self.get_endless_wave(wave_index)
+    }
+    fn get_endless_wave(&self, wave_index: usize) -> Option<Wave> {
+        let last_wave = self.waves.last()?;
+        let round = wave_index.checked_sub(self.waves.len())? + 1;
+        let growth = |rate: f32| 1.0 + rate * round as f32;
+
+        let (bosses, enemy_pool): (Vec<&WaveEnemies>, Vec<&WaveEnemies>) = self
+            .waves
+            .iter()
+            .flat_map(|wave| wave.get_enemies().iter())
+            .partition(|wave_enemies| wave_enemies.is_boss() == true);
+
+        if enemy_pool.is_empty() {
+            return None;
+        }
+
+        let scale_wave_enemies = |wave_enemies: &WaveEnemies, group: usize| {
+            let variant_path_indices = enemy_pool
+                .iter()
+                .chain(bosses.iter())
+                .filter(|pool_enemies| {
+                    pool_enemies.get_enemy_variant() == wave_enemies.get_enemy_variant()
+                })
+                .map(|pool_enemies| pool_enemies.get_path_index())
+                .fold(Vec::new(), |mut path_indices, path_index| {
+                    if path_indices.contains(&path_index) == false {
+                        path_indices.push(path_index);
+                    }
+                    path_indices
+                });
+
+            WaveEnemies {
+                count: if wave_enemies.is_boss() == true {
+                    wave_enemies.get_count()
+                } else {
+                    (wave_enemies.get_count() as f32 * growth(ENDLESS_COUNT_GROWTH)).ceil() as u32
+                },
+                path_index: variant_path_indices
+                    .get((round + group) % variant_path_indices.len().max(1))
+                    .copied()
+                    .unwrap_or(wave_enemies.get_path_index()),
+                health_multiplier: Some(
+                    wave_enemies.get_health_multiplier() * growth(ENDLESS_HEALTH_GROWTH),
+                ),
+                ..wave_enemies.clone()
+            }
+        };
+
+        let mut enemies = (0..ENDLESS_GROUPS_PER_WAVE)
+            .map(|group| {
+                let wave_enemies = enemy_pool[(round
+                    + group * enemy_pool.len() / ENDLESS_GROUPS_PER_WAVE)
+                    % enemy_pool.len()];
+
+                scale_wave_enemies(wave_enemies, group)
+            })
+            .collect::<Vec<_>>();
+
+        if round % ENDLESS_BOSS_WAVE_INTERVAL == 0 && bosses.is_empty() == false {
+            let boss_index = (round / ENDLESS_BOSS_WAVE_INTERVAL - 1) % bosses.len();
+            enemies.push(scale_wave_enemies(bosses[boss_index], 0));
+        }
+
+        Some(Wave {
+            reward: (last_wave.get_reward() as f32 * growth(ENDLESS_REWARD_GROWTH)).round() as u32,
+            enemies,
+        })
     }
     pub fn get_error(&self) -> Option<String> {
         self.error.clone()
@@ -405,8 +919,21 @@ impl Default for Level {
             player_health: 0,
             player_money: 0,
             viewport_size: None,
+            line_of_sight: false,
+            abilities: Vec::new(),
+            interest_rate: 0.0,
+            max_interest: None,
+            early_call_bonus: None,
+            overlapping_waves: false,
+            endless: false,
+            difficulty: LevelDifficulty::default(),
+            sell_price_multiplier: 1.0,
+            pathfinding: false,
+            maze: false,
             map_size: UVec2::default(),
             map: Vec::new(),
+            spawns: Vec::new(),
+            exits: Vec::new(),
             paths: Vec::new(),
             waves: Vec::new(),
             error: None,
@@ -444,6 +971,28 @@ impl Plugin for LevelsPlugin {
                 .build()
                 .unwrap(),
         );
+
+        app.insert_resource(
+            Persistent::<EndlessRecords>::builder()
+                .name("endless_records")
+                .format(StorageFormat::Ron)
+                .default(EndlessRecords::default())
+                .path(
+                    #[cfg(all(not(target_arch = "wasm32"), not(target_os = "android")))]
+                    directories::ProjectDirs::from("ru", "kicshikxo", "pico-td")
+                        .unwrap()
+                        .data_dir()
+                        .join("endless_records.ron"),
+                    #[cfg(target_arch = "wasm32")]
+                    std::path::Path::new("local").join("endless_records"),
+                    #[cfg(target_os = "android")]
+                    "/data/data/ru.kicshikxo.pico_td/files/endless_records.ron",
+                )
+                .revertible(true)
+                .revert_to_default_on_deserialization_errors(true)
+                .build()
+                .unwrap(),
+        );
     }
 }
 
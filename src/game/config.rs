@@ -2,12 +2,19 @@ use bevy::prelude::*;
 use bevy_persistent::prelude::*;
 use serde::{Deserialize, Serialize};
 
-use crate::game::entities::{enemy::path::EnemyPathVisibility, soldier::SoldierPlacement};
+use crate::game::{
+    assets::levels::{LevelDifficulty, LevelDifficultyModifiers},
+    entities::{enemy::path::EnemyPathVisibility, soldier::SoldierPlacement},
+};
 
 #[derive(Resource, Serialize, Deserialize)]
 pub struct GameConfig {
     soldier_placement: SoldierPlacement,
     enemy_path_visibility: EnemyPathVisibility,
+    #[serde(default)]
+    difficulty: LevelDifficulty,
+    #[serde(default)]
+    custom_difficulty_modifiers: LevelDifficultyModifiers,
 }
 
 impl Default for GameConfig {
@@ -15,6 +22,8 @@ impl Default for GameConfig {
         Self {
             soldier_placement: SoldierPlacement::default(),
             enemy_path_visibility: EnemyPathVisibility::default(),
+            difficulty: LevelDifficulty::default(),
+            custom_difficulty_modifiers: LevelDifficultyModifiers::default(),
         }
     }
 }
@@ -32,6 +41,22 @@ impl GameConfig {
     pub fn set_enemy_path_visibility(&mut self, visibility: EnemyPathVisibility) {
         self.enemy_path_visibility = visibility;
     }
+    pub fn get_difficulty(&self) -> LevelDifficulty {
+        self.difficulty
+    }
+    pub fn set_difficulty(&mut self, difficulty: LevelDifficulty) {
+        self.difficulty = difficulty;
+    }
+    pub fn get_custom_difficulty_modifiers(&self) -> &LevelDifficultyModifiers {
+        &self.custom_difficulty_modifiers
+    }
+    pub fn get_custom_difficulty_modifiers_mut(&mut self) -> &mut LevelDifficultyModifiers {
+        &mut self.custom_difficulty_modifiers
+    }
+    pub fn get_difficulty_modifiers(&self) -> LevelDifficultyModifiers {
+        self.difficulty
+            .get_modifiers(&self.custom_difficulty_modifiers)
+    }
 }
 
 pub struct GameConfigPlugin;
@@ -0,0 +1,100 @@
+use bevy::prelude::*;
+
+use crate::game::{
+    GameState, GameTilemap,
+    entities::{
+        enemy::{Enemy, health::EnemyHealth, spawn_enemies_on_path},
+        tile::movement::TileMovement,
+    },
+};
+
+#[derive(Component, Clone)]
+pub struct EnemyBoss {
+    phase: usize,
+    speed_multiplier: f32,
+}
+
+impl Default for EnemyBoss {
+    fn default() -> Self {
+        Self {
+            phase: 0,
+            speed_multiplier: 1.0,
+        }
+    }
+}
+
+impl EnemyBoss {
+    pub fn get_phase(&self) -> usize {
+        self.phase
+    }
+    pub fn set_next_phase(&mut self) {
+        self.phase = self.phase.saturating_add(1);
+    }
+    pub fn get_speed_multiplier(&self) -> f32 {
+        self.speed_multiplier
+    }
+    pub fn set_speed_multiplier(&mut self, speed_multiplier: f32) {
+        self.speed_multiplier = speed_multiplier;
+    }
+}
+
+pub struct EnemyBossPlugin;
+
+impl Plugin for EnemyBossPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            update_enemy_boss.run_if(in_state(GameState::InGame)),
+        );
+    }
+}
+
+fn update_enemy_boss(
+    mut commands: Commands,
+    game_tilemap: Single<Entity, With<GameTilemap>>,
+    mut enemies: Query<(
+        &Enemy,
+        &mut EnemyBoss,
+        &EnemyHealth,
+        &TileMovement,
+        &mut Sprite,
+        &Transform,
+    )>,
+) {
+    for (enemy, mut enemy_boss, enemy_health, enemy_movement, mut enemy_sprite, enemy_transform) in
+        enemies.iter_mut()
+    {
+        if enemy_health.get_current() == 0 || enemy_movement.get_progress() <= 0.0 {
+            continue;
+        }
+
+        while let Some(boss_phase) = enemy
+            .get_config()
+            .get_boss_phases()
+            .get(enemy_boss.get_phase())
+        {
+            if enemy_health.get_percentage() > boss_phase.get_health_threshold() {
+                break;
+            }
+
+            enemy_boss.set_speed_multiplier(boss_phase.get_speed_multiplier());
+
+            if let Some(sprite_variant) = boss_phase.get_sprite_variant() {
+                if let Some(texture_atlas) = enemy_sprite.texture_atlas.as_mut() {
+                    texture_atlas.index = sprite_variant as usize;
+                }
+            }
+
+            spawn_enemies_on_path(
+                &mut commands,
+                game_tilemap.entity(),
+                boss_phase.get_escorts(),
+                enemy,
+                enemy_movement,
+                enemy_transform,
+            );
+
+            enemy_boss.set_next_phase();
+        }
+    }
+}
@@ -1,13 +1,66 @@
 use bevy::prelude::*;
 
-use crate::game::assets::images::entity::EnemySpriteVariant;
+use crate::game::{
+    assets::images::entity::EnemySpriteVariant,
+    entities::{
+        enemy::{EnemyLevel, EnemyVariant},
+        soldier::projectile::DamageType,
+    },
+};
+
+pub struct EnemyArmor {
+    kinetic: f32,
+    explosive: f32,
+    piercing: f32,
+    fire: f32,
+}
+
+impl EnemyArmor {
+    pub fn get_resistance(&self, damage_type: DamageType) -> f32 {
+        match damage_type {
+            DamageType::Kinetic => self.kinetic,
+            DamageType::Explosive => self.explosive,
+            DamageType::Piercing => self.piercing,
+            DamageType::Fire => self.fire,
+        }
+    }
+    pub fn resolve_damage(&self, damage: u32, damage_type: DamageType) -> u32 {
+        (damage as f32 * (1.0 - self.get_resistance(damage_type)).clamp(0.0, 1.0)).round() as u32
+    }
+}
+
+pub struct EnemyBossPhase {
+    health_threshold: f32,
+    speed_multiplier: f32,
+    sprite_variant: Option<EnemySpriteVariant>,
+    escorts: &'static [EnemyVariant],
+}
+
+impl EnemyBossPhase {
+    pub fn get_health_threshold(&self) -> f32 {
+        self.health_threshold
+    }
+    pub fn get_speed_multiplier(&self) -> f32 {
+        self.speed_multiplier
+    }
+    pub fn get_sprite_variant(&self) -> Option<EnemySpriteVariant> {
+        self.sprite_variant
+    }
+    pub fn get_escorts(&self) -> &'static [EnemyVariant] {
+        self.escorts
+    }
+}
 
 pub struct EnemyConfig {
     health: u32,
     damage: u32,
     kill_reward: u32,
+    speed: f32,
     sprite_scale: f32,
     sprite_variant: EnemySpriteVariant,
+    armor: EnemyArmor,
+    death_spawns: &'static [EnemyVariant],
+    boss_phases: &'static [EnemyBossPhase],
 }
 
 impl EnemyConfig {
@@ -20,49 +73,116 @@ impl EnemyConfig {
     pub fn get_kill_reward(&self) -> u32 {
         self.kill_reward
     }
+    pub fn get_speed(&self) -> f32 {
+        self.speed
+    }
     pub fn get_sprite_scale(&self) -> Vec3 {
         Vec3::new(self.sprite_scale, self.sprite_scale, 1.0)
     }
     pub fn get_sprite_variant(&self) -> EnemySpriteVariant {
         self.sprite_variant
     }
+    pub fn get_armor(&self) -> &EnemyArmor {
+        &self.armor
+    }
+    pub fn get_death_spawns(&self) -> &'static [EnemyVariant] {
+        self.death_spawns
+    }
+    pub fn get_boss_phases(&self) -> &'static [EnemyBossPhase] {
+        self.boss_phases
+    }
 }
 
+const NO_ARMOR: EnemyArmor = EnemyArmor {
+    kinetic: 0.0,
+    explosive: 0.0,
+    piercing: 0.0,
+    fire: 0.0,
+};
+
+const LIGHT_ARMOR: EnemyArmor = EnemyArmor {
+    kinetic: 0.25,
+    explosive: 0.0,
+    piercing: 0.0,
+    fire: 0.0,
+};
+
+const HEAVY_ARMOR: EnemyArmor = EnemyArmor {
+    kinetic: 0.75,
+    explosive: 0.0,
+    piercing: 0.25,
+    fire: 0.5,
+};
+
+const AIR_ARMOR: EnemyArmor = EnemyArmor {
+    kinetic: 0.25,
+    explosive: 0.0,
+    piercing: 0.0,
+    fire: 0.25,
+};
+
+const SUBMARINE_ARMOR: EnemyArmor = EnemyArmor {
+    kinetic: 0.5,
+    explosive: 0.0,
+    piercing: 0.5,
+    fire: 1.0,
+};
+
 pub const DRON_LEVELS: [EnemyConfig; 5] = [
     EnemyConfig {
         health: 100,
         damage: 1,
         kill_reward: 1,
+        speed: 2.0,
         sprite_scale: 0.67,
         sprite_variant: EnemySpriteVariant::DronGray,
+        armor: NO_ARMOR,
+        death_spawns: &[],
+        boss_phases: &[],
     },
     EnemyConfig {
         health: 200,
         damage: 2,
         kill_reward: 2,
+        speed: 2.25,
         sprite_scale: 0.67,
         sprite_variant: EnemySpriteVariant::DronRed,
+        armor: NO_ARMOR,
+        death_spawns: &[],
+        boss_phases: &[],
     },
     EnemyConfig {
         health: 300,
         damage: 3,
         kill_reward: 3,
+        speed: 2.5,
         sprite_scale: 0.67,
         sprite_variant: EnemySpriteVariant::DronGreen,
+        armor: NO_ARMOR,
+        death_spawns: &[],
+        boss_phases: &[],
     },
     EnemyConfig {
         health: 400,
         damage: 4,
         kill_reward: 4,
+        speed: 2.75,
         sprite_scale: 0.67,
         sprite_variant: EnemySpriteVariant::DronBlue,
+        armor: NO_ARMOR,
+        death_spawns: &[],
+        boss_phases: &[],
     },
     EnemyConfig {
         health: 500,
         damage: 5,
         kill_reward: 5,
+        speed: 3.0,
         sprite_scale: 0.67,
         sprite_variant: EnemySpriteVariant::DronYellow,
+        armor: NO_ARMOR,
+        death_spawns: &[],
+        boss_phases: &[],
     },
 ];
 
@@ -71,36 +191,56 @@ pub const TRUCK_LEVELS: [EnemyConfig; 5] = [
         health: 100,
         damage: 1,
         kill_reward: 1,
+        speed: 1.25,
         sprite_scale: 0.75,
         sprite_variant: EnemySpriteVariant::TruckGray,
+        armor: LIGHT_ARMOR,
+        death_spawns: &[],
+        boss_phases: &[],
     },
     EnemyConfig {
         health: 200,
         damage: 2,
         kill_reward: 2,
+        speed: 1.5,
         sprite_scale: 0.75,
         sprite_variant: EnemySpriteVariant::TruckRed,
+        armor: LIGHT_ARMOR,
+        death_spawns: &[],
+        boss_phases: &[],
     },
     EnemyConfig {
         health: 300,
         damage: 3,
         kill_reward: 3,
+        speed: 1.75,
         sprite_scale: 0.75,
         sprite_variant: EnemySpriteVariant::TruckGreen,
+        armor: LIGHT_ARMOR,
+        death_spawns: &[],
+        boss_phases: &[],
     },
     EnemyConfig {
         health: 400,
         damage: 4,
         kill_reward: 4,
+        speed: 2.0,
         sprite_scale: 0.75,
         sprite_variant: EnemySpriteVariant::TruckBlue,
+        armor: LIGHT_ARMOR,
+        death_spawns: &[],
+        boss_phases: &[],
     },
     EnemyConfig {
         health: 500,
         damage: 5,
         kill_reward: 5,
+        speed: 2.25,
         sprite_scale: 0.75,
         sprite_variant: EnemySpriteVariant::TruckYellow,
+        armor: LIGHT_ARMOR,
+        death_spawns: &[],
+        boss_phases: &[],
     },
 ];
 
@@ -109,36 +249,84 @@ pub const TANK_LEVELS: [EnemyConfig; 5] = [
         health: 1000,
         damage: 10,
         kill_reward: 10,
+        speed: 0.75,
         sprite_scale: 0.9,
         sprite_variant: EnemySpriteVariant::TankGray,
+        armor: HEAVY_ARMOR,
+        death_spawns: &[],
+        boss_phases: &[],
     },
     EnemyConfig {
         health: 1200,
         damage: 12,
         kill_reward: 12,
+        speed: 0.9,
         sprite_scale: 0.9,
         sprite_variant: EnemySpriteVariant::TankRed,
+        armor: HEAVY_ARMOR,
+        death_spawns: &[],
+        boss_phases: &[],
     },
     EnemyConfig {
         health: 1400,
         damage: 14,
         kill_reward: 14,
+        speed: 1.05,
         sprite_scale: 0.9,
         sprite_variant: EnemySpriteVariant::TankGreen,
+        armor: HEAVY_ARMOR,
+        death_spawns: &[
+            EnemyVariant::Truck(EnemyLevel::Mk1),
+            EnemyVariant::Truck(EnemyLevel::Mk1),
+        ],
+        boss_phases: &[],
     },
     EnemyConfig {
         health: 1600,
         damage: 16,
         kill_reward: 16,
+        speed: 1.2,
         sprite_scale: 0.9,
         sprite_variant: EnemySpriteVariant::TankBlue,
+        armor: HEAVY_ARMOR,
+        death_spawns: &[
+            EnemyVariant::Truck(EnemyLevel::Mk2),
+            EnemyVariant::Truck(EnemyLevel::Mk2),
+        ],
+        boss_phases: &[],
     },
     EnemyConfig {
         health: 1800,
         damage: 18,
         kill_reward: 18,
+        speed: 1.35,
         sprite_scale: 0.9,
         sprite_variant: EnemySpriteVariant::TankYellow,
+        armor: HEAVY_ARMOR,
+        death_spawns: &[
+            EnemyVariant::Truck(EnemyLevel::Mk3),
+            EnemyVariant::Truck(EnemyLevel::Mk3),
+        ],
+        boss_phases: &[
+            EnemyBossPhase {
+                health_threshold: 0.66,
+                speed_multiplier: 1.25,
+                sprite_variant: Some(EnemySpriteVariant::TankRed),
+                escorts: &[
+                    EnemyVariant::Truck(EnemyLevel::Mk3),
+                    EnemyVariant::Truck(EnemyLevel::Mk3),
+                ],
+            },
+            EnemyBossPhase {
+                health_threshold: 0.33,
+                speed_multiplier: 1.5,
+                sprite_variant: Some(EnemySpriteVariant::TankRed),
+                escorts: &[
+                    EnemyVariant::Tank(EnemyLevel::Mk2),
+                    EnemyVariant::Tank(EnemyLevel::Mk2),
+                ],
+            },
+        ],
     },
 ];
 
@@ -147,36 +335,59 @@ pub const PLANE_LEVELS: [EnemyConfig; 5] = [
         health: 500,
         damage: 5,
         kill_reward: 5,
+        speed: 3.0,
         sprite_scale: 1.0,
         sprite_variant: EnemySpriteVariant::PlaneGray,
+        armor: AIR_ARMOR,
+        death_spawns: &[],
+        boss_phases: &[],
     },
     EnemyConfig {
         health: 600,
         damage: 6,
         kill_reward: 6,
+        speed: 3.25,
         sprite_scale: 1.0,
         sprite_variant: EnemySpriteVariant::PlaneRed,
+        armor: AIR_ARMOR,
+        death_spawns: &[],
+        boss_phases: &[],
     },
     EnemyConfig {
         health: 700,
         damage: 7,
         kill_reward: 7,
+        speed: 3.5,
         sprite_scale: 1.0,
         sprite_variant: EnemySpriteVariant::PlaneGreen,
+        armor: AIR_ARMOR,
+        death_spawns: &[],
+        boss_phases: &[],
     },
     EnemyConfig {
         health: 800,
         damage: 8,
         kill_reward: 8,
+        speed: 3.75,
         sprite_scale: 1.0,
         sprite_variant: EnemySpriteVariant::PlaneBlue,
+        armor: AIR_ARMOR,
+        death_spawns: &[],
+        boss_phases: &[],
     },
     EnemyConfig {
         health: 900,
         damage: 9,
         kill_reward: 9,
+        speed: 4.0,
         sprite_scale: 1.0,
         sprite_variant: EnemySpriteVariant::PlaneYellow,
+        armor: AIR_ARMOR,
+        death_spawns: &[
+            EnemyVariant::Dron(EnemyLevel::Mk3),
+            EnemyVariant::Dron(EnemyLevel::Mk3),
+        ],
+        boss_phases: &[],
     },
 ];
 
@@ -185,36 +396,65 @@ pub const HELICOPTER_LEVELS: [EnemyConfig; 5] = [
         health: 300,
         damage: 3,
         kill_reward: 3,
+        speed: 2.25,
         sprite_scale: 1.0,
         sprite_variant: EnemySpriteVariant::HelicopterGray,
+        armor: AIR_ARMOR,
+        death_spawns: &[],
+        boss_phases: &[],
     },
     EnemyConfig {
         health: 400,
         damage: 4,
         kill_reward: 4,
+        speed: 2.5,
         sprite_scale: 1.0,
         sprite_variant: EnemySpriteVariant::HelicopterRed,
+        armor: AIR_ARMOR,
+        death_spawns: &[],
+        boss_phases: &[],
     },
     EnemyConfig {
         health: 500,
         damage: 5,
         kill_reward: 5,
+        speed: 2.75,
         sprite_scale: 1.0,
         sprite_variant: EnemySpriteVariant::HelicopterGreen,
+        armor: AIR_ARMOR,
+        death_spawns: &[],
+        boss_phases: &[],
     },
     EnemyConfig {
         health: 600,
         damage: 6,
         kill_reward: 6,
+        speed: 3.0,
         sprite_scale: 1.0,
         sprite_variant: EnemySpriteVariant::HelicopterBlue,
+        armor: AIR_ARMOR,
+        death_spawns: &[],
+        boss_phases: &[],
     },
     EnemyConfig {
         health: 700,
         damage: 7,
         kill_reward: 7,
+        speed: 3.25,
         sprite_scale: 1.0,
         sprite_variant: EnemySpriteVariant::HelicopterYellow,
+        armor: AIR_ARMOR,
+        death_spawns: &[],
+        boss_phases: &[EnemyBossPhase {
+            health_threshold: 0.5,
+            speed_multiplier: 1.5,
+            sprite_variant: Some(EnemySpriteVariant::HelicopterRed),
+            escorts: &[
+                EnemyVariant::Dron(EnemyLevel::Mk3),
+                EnemyVariant::Dron(EnemyLevel::Mk3),
+                EnemyVariant::Dron(EnemyLevel::Mk3),
+            ],
+        }],
     },
 ];
 
@@ -223,36 +463,56 @@ pub const BOAT_LEVELS: [EnemyConfig; 5] = [
         health: 200,
         damage: 2,
         kill_reward: 2,
+        speed: 1.5,
         sprite_scale: 0.75,
         sprite_variant: EnemySpriteVariant::BoatGray,
+        armor: LIGHT_ARMOR,
+        death_spawns: &[],
+        boss_phases: &[],
     },
     EnemyConfig {
         health: 300,
         damage: 3,
         kill_reward: 3,
+        speed: 1.75,
         sprite_scale: 0.75,
         sprite_variant: EnemySpriteVariant::BoatRed,
+        armor: LIGHT_ARMOR,
+        death_spawns: &[],
+        boss_phases: &[],
     },
     EnemyConfig {
         health: 400,
         damage: 4,
         kill_reward: 4,
+        speed: 2.0,
         sprite_scale: 0.75,
         sprite_variant: EnemySpriteVariant::BoatGreen,
+        armor: LIGHT_ARMOR,
+        death_spawns: &[],
+        boss_phases: &[],
     },
     EnemyConfig {
         health: 500,
         damage: 5,
         kill_reward: 5,
+        speed: 2.25,
         sprite_scale: 0.75,
         sprite_variant: EnemySpriteVariant::BoatBlue,
+        armor: LIGHT_ARMOR,
+        death_spawns: &[],
+        boss_phases: &[],
     },
     EnemyConfig {
         health: 600,
         damage: 6,
         kill_reward: 6,
+        speed: 2.5,
         sprite_scale: 0.75,
         sprite_variant: EnemySpriteVariant::BoatYellow,
+        armor: LIGHT_ARMOR,
+        death_spawns: &[],
+        boss_phases: &[],
     },
 ];
 
@@ -261,35 +521,66 @@ pub const SUBMARINE_LEVELS: [EnemyConfig; 5] = [
         health: 500,
         damage: 5,
         kill_reward: 5,
+        speed: 1.0,
         sprite_scale: 0.75,
         sprite_variant: EnemySpriteVariant::SubmarineGray,
+        armor: SUBMARINE_ARMOR,
+        death_spawns: &[],
+        boss_phases: &[],
     },
     EnemyConfig {
         health: 600,
         damage: 6,
         kill_reward: 6,
+        speed: 1.2,
         sprite_scale: 0.75,
         sprite_variant: EnemySpriteVariant::SubmarineRed,
+        armor: SUBMARINE_ARMOR,
+        death_spawns: &[],
+        boss_phases: &[],
     },
     EnemyConfig {
         health: 700,
         damage: 7,
         kill_reward: 7,
+        speed: 1.4,
         sprite_scale: 0.75,
         sprite_variant: EnemySpriteVariant::SubmarineGreen,
+        armor: SUBMARINE_ARMOR,
+        death_spawns: &[],
+        boss_phases: &[],
     },
     EnemyConfig {
         health: 800,
         damage: 8,
         kill_reward: 8,
+        speed: 1.6,
         sprite_scale: 0.75,
         sprite_variant: EnemySpriteVariant::SubmarineBlue,
+        armor: SUBMARINE_ARMOR,
+        death_spawns: &[],
+        boss_phases: &[],
     },
     EnemyConfig {
         health: 900,
         damage: 9,
         kill_reward: 9,
+        speed: 1.8,
         sprite_scale: 0.75,
         sprite_variant: EnemySpriteVariant::SubmarineYellow,
+        armor: SUBMARINE_ARMOR,
+        death_spawns: &[
+            EnemyVariant::Boat(EnemyLevel::Mk2),
+            EnemyVariant::Boat(EnemyLevel::Mk2),
+        ],
+        boss_phases: &[EnemyBossPhase {
+            health_threshold: 0.5,
+            speed_multiplier: 0.75,
+            sprite_variant: Some(EnemySpriteVariant::SubmarineRed),
+            escorts: &[
+                EnemyVariant::Boat(EnemyLevel::Mk3),
+                EnemyVariant::Boat(EnemyLevel::Mk3),
+            ],
+        }],
     },
 ];
@@ -1,7 +1,10 @@
+pub mod boss;
 pub mod config;
 pub mod health;
 pub mod health_bar;
 pub mod path;
+pub mod route;
+pub mod status_effect;
 
 use std::{
     f32::consts::{FRAC_PI_2, PI, TAU},
@@ -16,6 +19,7 @@ use crate::game::{
     GameState, GameTilemap,
     entities::{
         enemy::{
+            boss::{EnemyBoss, EnemyBossPlugin},
             config::{
                 BOAT_LEVELS, DRON_LEVELS, EnemyConfig, HELICOPTER_LEVELS, PLANE_LEVELS,
                 SUBMARINE_LEVELS, TANK_LEVELS, TRUCK_LEVELS,
@@ -23,6 +27,8 @@ use crate::game::{
             health::EnemyHealth,
             health_bar::{EnemyHealthBar, EnemyHealthBarPlugin},
             path::EnemyPathPlugin,
+            route::EnemyRoutesPlugin,
+            status_effect::{EnemyStatusEffects, EnemyStatusEffectsPlugin},
         },
         tile::{
             movement::TileMovement,
@@ -34,6 +40,10 @@ use crate::game::{
     speed::GameSpeed,
 };
 
+const ENEMY_SPAWN_SPACING: f32 = 0.5;
+const BOSS_HEALTH_MULTIPLIER: u32 = 5;
+const BOSS_SPRITE_SCALE_MULTIPLIER: f32 = 1.25;
+
 #[derive(Clone, Copy, PartialEq, Deserialize)]
 pub enum EnemyLevel {
     Mk1,
@@ -53,6 +63,32 @@ impl EnemyLevel {
             EnemyLevel::Mk5 => 4,
         }
     }
+    pub fn get_previous(&self) -> Option<EnemyLevel> {
+        match self {
+            EnemyLevel::Mk1 => None,
+            EnemyLevel::Mk2 => Some(EnemyLevel::Mk1),
+            EnemyLevel::Mk3 => Some(EnemyLevel::Mk2),
+            EnemyLevel::Mk4 => Some(EnemyLevel::Mk3),
+            EnemyLevel::Mk5 => Some(EnemyLevel::Mk4),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum EnemyDomain {
+    Ground,
+    Air,
+    Naval,
+}
+
+impl EnemyDomain {
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            EnemyDomain::Ground => "enemy.domain.ground",
+            EnemyDomain::Air => "enemy.domain.air",
+            EnemyDomain::Naval => "enemy.domain.naval",
+        }
+    }
 }
 
 #[derive(Clone, Copy, PartialEq, Deserialize)]
@@ -67,6 +103,17 @@ pub enum EnemyVariant {
 }
 
 impl EnemyVariant {
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            EnemyVariant::Dron(_) => "enemy.variant.dron",
+            EnemyVariant::Truck(_) => "enemy.variant.truck",
+            EnemyVariant::Tank(_) => "enemy.variant.tank",
+            EnemyVariant::Plane(_) => "enemy.variant.plane",
+            EnemyVariant::Helicopter(_) => "enemy.variant.helicopter",
+            EnemyVariant::Boat(_) => "enemy.variant.boat",
+            EnemyVariant::Submarine(_) => "enemy.variant.submarine",
+        }
+    }
     pub fn get_config(&self) -> &EnemyConfig {
         match self {
             EnemyVariant::Dron(level) => DRON_LEVELS.get(level.as_index()).unwrap(),
@@ -78,12 +125,53 @@ impl EnemyVariant {
             EnemyVariant::Submarine(level) => SUBMARINE_LEVELS.get(level.as_index()).unwrap(),
         }
     }
+    pub fn get_domain(&self) -> EnemyDomain {
+        match self {
+            EnemyVariant::Truck(_) | EnemyVariant::Tank(_) => EnemyDomain::Ground,
+            EnemyVariant::Dron(_) | EnemyVariant::Plane(_) | EnemyVariant::Helicopter(_) => {
+                EnemyDomain::Air
+            }
+            EnemyVariant::Boat(_) | EnemyVariant::Submarine(_) => EnemyDomain::Naval,
+        }
+    }
+    pub fn get_level(&self) -> EnemyLevel {
+        match self {
+            EnemyVariant::Dron(level)
+            | EnemyVariant::Truck(level)
+            | EnemyVariant::Tank(level)
+            | EnemyVariant::Plane(level)
+            | EnemyVariant::Helicopter(level)
+            | EnemyVariant::Boat(level)
+            | EnemyVariant::Submarine(level) => *level,
+        }
+    }
+    pub fn with_level(&self, level: EnemyLevel) -> EnemyVariant {
+        match self {
+            EnemyVariant::Dron(_) => EnemyVariant::Dron(level),
+            EnemyVariant::Truck(_) => EnemyVariant::Truck(level),
+            EnemyVariant::Tank(_) => EnemyVariant::Tank(level),
+            EnemyVariant::Plane(_) => EnemyVariant::Plane(level),
+            EnemyVariant::Helicopter(_) => EnemyVariant::Helicopter(level),
+            EnemyVariant::Boat(_) => EnemyVariant::Boat(level),
+            EnemyVariant::Submarine(_) => EnemyVariant::Submarine(level),
+        }
+    }
+    pub fn get_previous_level(&self) -> Option<EnemyVariant> {
+        self.get_level()
+            .get_previous()
+            .map(|level| self.with_level(level))
+    }
 }
 
 #[derive(Component)]
-#[require(EnemyHealth, TileMovement, TilePosition)]
+#[require(EnemyHealth, EnemyStatusEffects, TileMovement, TilePosition)]
 pub struct Enemy {
     variant: EnemyVariant,
+    layered: bool,
+    boss: bool,
+    wave: usize,
+    health_multiplier: f32,
+    duration_override: bool,
     update_required: bool,
 }
 
@@ -92,9 +180,65 @@ impl Enemy {
     pub fn new(variant: EnemyVariant) -> Self {
         Self {
             variant,
+            layered: false,
+            boss: false,
+            wave: 0,
+            health_multiplier: 1.0,
+            duration_override: false,
             update_required: true,
         }
     }
+    pub fn with_layered(mut self, layered: bool) -> Self {
+        self.layered = layered;
+        self
+    }
+    pub fn is_layered(&self) -> bool {
+        self.layered
+    }
+    pub fn with_boss(mut self, boss: bool) -> Self {
+        self.boss = boss;
+        self
+    }
+    pub fn is_boss(&self) -> bool {
+        self.boss
+    }
+    pub fn with_wave(mut self, wave: usize) -> Self {
+        self.wave = wave;
+        self
+    }
+    pub fn get_wave(&self) -> usize {
+        self.wave
+    }
+    pub fn with_health_multiplier(mut self, health_multiplier: f32) -> Self {
+        self.health_multiplier = health_multiplier;
+        self
+    }
+    pub fn get_health_multiplier(&self) -> f32 {
+        self.health_multiplier
+    }
+    pub fn with_duration_override(mut self, duration_override: bool) -> Self {
+        self.duration_override = duration_override;
+        self
+    }
+    pub fn has_duration_override(&self) -> bool {
+        self.duration_override
+    }
+    pub fn get_health(&self) -> u32 {
+        let health = if self.is_boss() == true {
+            self.get_config().get_health() * BOSS_HEALTH_MULTIPLIER
+        } else {
+            self.get_config().get_health()
+        };
+
+        (health as f32 * self.get_health_multiplier()).round() as u32
+    }
+    pub fn get_sprite_scale(&self) -> Vec3 {
+        if self.is_boss() == true {
+            self.get_config().get_sprite_scale() * BOSS_SPRITE_SCALE_MULTIPLIER
+        } else {
+            self.get_config().get_sprite_scale()
+        }
+    }
     pub fn get_variant(&self) -> EnemyVariant {
         self.variant
     }
@@ -128,7 +272,13 @@ pub struct EnemyPlugin;
 
 impl Plugin for EnemyPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((EnemyHealthBarPlugin, EnemyPathPlugin));
+        app.add_plugins((
+            EnemyHealthBarPlugin,
+            EnemyPathPlugin,
+            EnemyRoutesPlugin,
+            EnemyStatusEffectsPlugin,
+            EnemyBossPlugin,
+        ));
 
         app.add_systems(PreUpdate, init_enemy);
 
@@ -150,10 +300,14 @@ fn init_enemy(
 ) {
     for (enemy_entity, enemy) in enemies.iter() {
         commands.entity(enemy_entity).insert((
-            EnemyHealth::new(enemy.get_config().get_health()),
+            EnemyHealth::new(enemy.get_health()),
             TileSprite::new(enemy.get_variant().into()),
         ));
 
+        if enemy.is_boss() == true {
+            commands.entity(enemy_entity).insert(EnemyBoss::default());
+        }
+
         commands
             .entity(game_tilemap.entity())
             .with_child(EnemyHealthBar::new(enemy_entity));
@@ -190,20 +344,26 @@ fn update_enemy_movement(
     {
         if enemy_movement.get_progress() >= 1.0 {
             commands.entity(enemy_entity).despawn();
-            player.get_health_mut().damage(enemy.get_damage());
+            if enemy.is_boss() == true {
+                let player_health = player.get_health().get_current();
+                player.get_health_mut().damage(player_health);
+            } else {
+                player.get_health_mut().damage(enemy.get_damage());
+            }
             continue;
         }
         if enemy_movement.get_progress() == 0.0 {
             continue;
         }
         if enemy.get_update_required() == true {
-            enemy_tile_sprite.set_variant(TileSpriteVariant::Enemy(enemy.get_variant().into()));
-            let config = enemy.get_config();
-            enemy_health.set_max(config.get_health());
-            enemy_health.heal(config.get_health());
-            enemy_tile_position.set_z((enemy_movement.get_speed() * 1e-3).clamp(0.0, 1.0));
-            enemy_transform.scale = config.get_sprite_scale();
-            enemy.set_update_required(false);
+            update_enemy_variant(
+                &mut enemy,
+                &mut enemy_health,
+                enemy_movement,
+                &mut enemy_tile_position,
+                &mut enemy_tile_sprite,
+                &mut enemy_transform,
+            );
         }
         enemy_tile_position.set_from_vec2(enemy_movement.get_position());
 
@@ -219,24 +379,123 @@ fn update_enemy_movement(
     }
 }
 
+fn update_enemy_variant(
+    enemy: &mut Enemy,
+    enemy_health: &mut EnemyHealth,
+    enemy_movement: &TileMovement,
+    enemy_tile_position: &mut TilePosition,
+    enemy_tile_sprite: &mut TileSprite,
+    enemy_transform: &mut Transform,
+) {
+    enemy_tile_sprite.set_variant(TileSpriteVariant::Enemy(enemy.get_variant().into()));
+    enemy_health.set_max(enemy.get_health());
+    enemy_health.heal(enemy.get_health());
+    enemy_tile_position.set_z((enemy_movement.get_speed() * 1e-3).clamp(0.0, 1.0));
+    enemy_transform.scale = enemy.get_sprite_scale();
+    enemy.set_update_required(false);
+}
+
+pub fn spawn_enemies_on_path(
+    commands: &mut Commands,
+    game_tilemap_entity: Entity,
+    enemy_variants: &[EnemyVariant],
+    enemy: &Enemy,
+    enemy_movement: &TileMovement,
+    enemy_transform: &Transform,
+) {
+    for (index, enemy_variant) in enemy_variants.iter().enumerate() {
+        let progress_offset =
+            ENEMY_SPAWN_SPACING * index as f32 / enemy_movement.get_length().max(1.0);
+
+        commands.entity(game_tilemap_entity).with_child((
+            Enemy::new(*enemy_variant)
+                .with_wave(enemy.get_wave())
+                .with_health_multiplier(enemy.get_health_multiplier()),
+            TileMovement::from_speed(
+                enemy_movement.get_path().clone(),
+                enemy_variant.get_config().get_speed(),
+                None,
+            )
+            .with_progress((enemy_movement.get_progress() - progress_offset).max(f32::EPSILON)),
+            Transform::from_scale(Vec3::ZERO).with_rotation(enemy_transform.rotation),
+        ));
+    }
+}
+
 fn update_enemy_health(
     mut commands: Commands,
-    mut enemies: Query<(Entity, &Enemy, &mut EnemyHealth, &mut Sprite, &Transform), With<Enemy>>,
+    game_tilemap: Single<Entity, With<GameTilemap>>,
+    mut enemies: Query<
+        (
+            Entity,
+            &mut Enemy,
+            &mut EnemyHealth,
+            &EnemyStatusEffects,
+            &mut TileMovement,
+            &mut TilePosition,
+            &mut TileSprite,
+            &mut Sprite,
+            &mut Transform,
+        ),
+        With<Enemy>,
+    >,
     mut enemy_health_bars: Query<&mut EnemyHealthBar>,
     mut player: ResMut<Player>,
     game_speed: Res<GameSpeed>,
     time: Res<Time>,
 ) {
-    for (enemy_entity, enemy, mut enemy_health, mut enemy_sprite, _enemy_transform) in
-        enemies.iter_mut()
+    for (
+        enemy_entity,
+        mut enemy,
+        mut enemy_health,
+        enemy_status_effects,
+        mut enemy_movement,
+        mut enemy_tile_position,
+        mut enemy_tile_sprite,
+        mut enemy_sprite,
+        mut enemy_transform,
+    ) in enemies.iter_mut()
     {
+        if enemy_health.get_current() == 0 && enemy.is_layered() == true {
+            if let Some(previous_level_variant) = enemy.get_previous_level() {
+                player.get_money_mut().increase(enemy.get_kill_reward());
+
+                enemy.set_variant(previous_level_variant);
+
+                if enemy.has_duration_override() == false {
+                    enemy_movement.set_speed(enemy.get_config().get_speed());
+                }
+
+                update_enemy_variant(
+                    &mut enemy,
+                    &mut enemy_health,
+                    &enemy_movement,
+                    &mut enemy_tile_position,
+                    &mut enemy_tile_sprite,
+                    &mut enemy_transform,
+                );
+            }
+        }
         if enemy_health.get_current() == 0 {
             commands.entity(enemy_entity).despawn();
             player.get_money_mut().increase(enemy.get_kill_reward());
+
+            spawn_enemies_on_path(
+                &mut commands,
+                game_tilemap.entity(),
+                enemy.get_config().get_death_spawns(),
+                &enemy,
+                &enemy_movement,
+                &enemy_transform,
+            );
             continue;
         }
 
-        let target_enemy_sprite_color = LinearRgba::WHITE.to_vec3();
+        let target_enemy_sprite_color = enemy_status_effects
+            .get_tint()
+            .unwrap_or(Color::WHITE)
+            .to_linear()
+            .to_vec3();
         let current_enemy_sprite_color = enemy_sprite.color.to_linear().to_vec3();
 
         if current_enemy_sprite_color != target_enemy_sprite_color {
@@ -6,9 +6,13 @@ use serde::{Deserialize, Serialize};
 
 use crate::game::{
     GameState, GameTilemap,
-    assets::levels::Level,
+    assets::levels::{Level, Path, Wave},
     config::GameConfig,
-    entities::{tile::position::TilePosition, tilemap::Tilemap},
+    entities::{
+        enemy::{EnemyDomain, route::EnemyRoutes},
+        tile::position::TilePosition,
+        tilemap::Tilemap,
+    },
     meshes::rounded_rectangle::RoundedRectangle,
     waves::{GameWaves, WaveState},
 };
@@ -49,6 +53,7 @@ impl EnemyPathVisibility {
 #[derive(Component)]
 struct EnemyPath {
     path_index: usize,
+    domain: Option<EnemyDomain>,
     visible: bool,
 }
 
@@ -56,9 +61,14 @@ impl EnemyPath {
     pub fn new(path_index: usize) -> Self {
         Self {
             path_index,
+            domain: None,
             visible: false,
         }
     }
+    pub fn with_domain(mut self, domain: Option<EnemyDomain>) -> Self {
+        self.domain = domain;
+        self
+    }
     pub fn with_visibility(mut self, visible: bool) -> Self {
         self.visible = visible;
         self
@@ -66,6 +76,17 @@ impl EnemyPath {
     pub fn get_path_index(&self) -> usize {
         self.path_index
     }
+    pub fn get_domain(&self) -> Option<EnemyDomain> {
+        self.domain
+    }
+    pub fn is_used_by(&self, wave: &Wave) -> bool {
+        wave.get_enemies().iter().any(|wave_enemies| {
+            wave_enemies.get_path_index() == self.path_index
+                && self
+                    .domain
+                    .is_none_or(|domain| domain == wave_enemies.get_enemy_variant().get_domain())
+        })
+    }
     pub fn get_visible(&self) -> bool {
         self.visible
     }
@@ -80,6 +101,12 @@ impl Plugin for EnemyPathPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(OnExit(GameState::Start), init_enemy_paths);
 
+        app.add_systems(
+            Update,
+            init_enemy_paths
+                .run_if(in_state(GameState::InGame).and(resource_changed::<EnemyRoutes>)),
+        );
+
         app.add_systems(
             Update,
             update_enemy_paths.run_if(
@@ -100,6 +127,8 @@ fn init_enemy_paths(
     mut enemy_paths: Query<Entity, With<EnemyPath>>,
     game_tilemap: Single<(Entity, &Tilemap), With<GameTilemap>>,
     selected_level: Res<Level>,
+    enemy_routes: Res<EnemyRoutes>,
+    game_waves: Res<GameWaves>,
     game_config: Res<Persistent<GameConfig>>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
@@ -110,13 +139,29 @@ fn init_enemy_paths(
 
     let (game_tilemap_entity, game_tilemap) = game_tilemap.into_inner();
 
-    for (path_index, path) in selected_level.get_paths().iter().enumerate() {
-        let path_visible = selected_level.get_wave(0).map_or(false, |wave| {
-            wave.get_enemies()
-                .iter()
-                .any(|wave_enemies| wave_enemies.get_path_index() == path_index)
-        }) && game_config.get_enemy_path_visibility()
-            != EnemyPathVisibility::NeverVisible;
+    let paths: Vec<(EnemyPath, &Path)> = if selected_level.is_pathfinding() == true {
+        enemy_routes
+            .get_routes()
+            .iter()
+            .map(|route| {
+                (
+                    EnemyPath::new(route.get_spawn_index()).with_domain(Some(route.get_domain())),
+                    route.get_path(),
+                )
+            })
+            .collect()
+    } else {
+        selected_level
+            .get_paths()
+            .iter()
+            .enumerate()
+            .map(|(path_index, path)| (EnemyPath::new(path_index), path))
+            .collect()
+    };
+
+    for (path_layer, (enemy_path, path)) in paths.iter().enumerate() {
+        let path_visible =
+            is_enemy_path_visible(enemy_path, &selected_level, &game_waves, &game_config);
 
         for segment in path.get_points().windows(2) {
             let (start_position, end_position) = (segment[0], segment[1]);
@@ -126,7 +171,9 @@ fn init_enemy_paths(
             let segment_angle = (end_position - start_position).angle_to(Vec2::X);
 
             commands.entity(game_tilemap_entity).with_child((
-                EnemyPath::new(path_index).with_visibility(path_visible),
+                EnemyPath::new(enemy_path.get_path_index())
+                    .with_domain(enemy_path.get_domain())
+                    .with_visibility(path_visible),
                 Mesh2d(meshes.add(RoundedRectangle::new(
                     segment_length * game_tilemap.get_tile_size() as f32,
                     2.0,
@@ -142,7 +189,7 @@ fn init_enemy_paths(
                     }),
                 ),
                 TilePosition::from_vec2(middle_position)
-                    .with_z((-1.0 + path_index as f32 * 1e-3).clamp(-1.0, 0.0)),
+                    .with_z((-1.0 + path_layer as f32 * 1e-3).clamp(-1.0, 0.0)),
                 Transform::from_rotation(Quat::from_rotation_z(segment_angle)),
             ));
         }
@@ -155,12 +202,26 @@ fn update_enemy_paths(
     game_waves: Res<GameWaves>,
     game_config: Res<Persistent<GameConfig>>,
 ) {
+    for mut enemy_path in enemy_paths.iter_mut() {
+        let path_visible =
+            is_enemy_path_visible(&enemy_path, &selected_level, &game_waves, &game_config);
+
+        enemy_path.set_visible(path_visible);
+    }
+}
+
+fn is_enemy_path_visible(
+    enemy_path: &EnemyPath,
+    selected_level: &Level,
+    game_waves: &GameWaves,
+    game_config: &GameConfig,
+) -> bool {
     let Some(wave) = selected_level.get_wave(if game_waves.get_state() == WaveState::Completed {
         game_waves.get_current().saturating_add(1)
     } else {
         game_waves.get_current()
     }) else {
-        return;
+        return false;
     };
 
     let suitable_wave_state = match game_config.get_enemy_path_visibility() {
@@ -174,16 +235,7 @@ fn update_enemy_paths(
         EnemyPathVisibility::NeverVisible => false,
     };
 
-    for mut enemy_path in enemy_paths.iter_mut() {
-        let path_used = wave
-            .get_enemies()
-            .iter()
-            .any(|wave_enemies| wave_enemies.get_path_index() == enemy_path.get_path_index());
-
-        let path_visible = path_used && suitable_wave_state;
-
-        enemy_path.set_visible(path_visible);
-    }
+    enemy_path.is_used_by(&wave) && suitable_wave_state
 }
 
 fn update_enemy_paths_alpha(
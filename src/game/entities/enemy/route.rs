@@ -0,0 +1,307 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet},
+};
+
+use bevy::prelude::*;
+
+use crate::game::{
+    GameState,
+    assets::levels::{Level, Path},
+    entities::{
+        enemy::{Enemy, EnemyDomain},
+        soldier::Soldier,
+        tile::{movement::TileMovement, position::TilePosition},
+    },
+};
+
+#[derive(Clone)]
+pub struct EnemyRoute {
+    spawn_index: usize,
+    domain: EnemyDomain,
+    path: Path,
+}
+
+impl EnemyRoute {
+    pub fn get_spawn_index(&self) -> usize {
+        self.spawn_index
+    }
+    pub fn get_domain(&self) -> EnemyDomain {
+        self.domain
+    }
+    pub fn get_path(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct EnemyRoutes {
+    routes: Vec<EnemyRoute>,
+}
+
+impl EnemyRoutes {
+    pub fn update(&mut self, level: &Level, blocked_tiles: &HashSet<IVec2>) {
+        self.routes.clear();
+
+        if level.is_pathfinding() == false {
+            return;
+        }
+
+        for (spawn_index, spawn) in level.get_spawns().iter().enumerate() {
+            for domain in [EnemyDomain::Ground, EnemyDomain::Naval, EnemyDomain::Air] {
+                let Some(points) =
+                    find_route(level, *spawn, level.get_exits(), domain, blocked_tiles)
+                else {
+                    continue;
+                };
+                self.routes.push(EnemyRoute {
+                    spawn_index,
+                    domain,
+                    path: Path::new(get_route_color(domain), points),
+                });
+            }
+        }
+    }
+    pub fn get_routes(&self) -> &Vec<EnemyRoute> {
+        &self.routes
+    }
+    pub fn get_route(&self, spawn_index: usize, domain: EnemyDomain) -> Option<&EnemyRoute> {
+        self.routes
+            .iter()
+            .find(|route| route.get_spawn_index() == spawn_index && route.get_domain() == domain)
+    }
+    pub fn get_path(&self, level: &Level, path_index: usize, domain: EnemyDomain) -> Path {
+        if level.is_pathfinding() == false {
+            return level.get_path(path_index);
+        }
+        self.get_route(path_index, domain)
+            .map(|route| route.get_path().clone())
+            .unwrap_or_default()
+    }
+    pub fn is_blocked_by(
+        &self,
+        level: &Level,
+        blocked_tiles: &HashSet<IVec2>,
+        tile_position: IVec2,
+        enemy_positions: &[(IVec2, EnemyDomain)],
+    ) -> bool {
+        if level.get_spawns().contains(&tile_position) || level.get_exits().contains(&tile_position)
+        {
+            return true;
+        }
+
+        let mut blocked_tiles = blocked_tiles.clone();
+        blocked_tiles.insert(tile_position);
+
+        let spawn_positions = self
+            .routes
+            .iter()
+            .filter_map(|route| {
+                level
+                    .get_spawns()
+                    .get(route.get_spawn_index())
+                    .map(|spawn| (*spawn, route.get_domain()))
+            })
+            .collect::<Vec<_>>();
+
+        spawn_positions
+            .iter()
+            .chain(enemy_positions.iter())
+            .filter(|(_, domain)| *domain != EnemyDomain::Air)
+            .any(|(start, domain)| {
+                find_route(level, *start, level.get_exits(), *domain, &blocked_tiles).is_none()
+            })
+    }
+}
+
+pub struct EnemyRoutesPlugin;
+
+impl Plugin for EnemyRoutesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EnemyRoutes>();
+
+        app.add_systems(OnEnter(GameState::Start), init_enemy_routes);
+
+        app.add_systems(
+            Update,
+            update_enemy_routes.run_if(in_state(GameState::InGame)),
+        );
+    }
+}
+
+fn init_enemy_routes(mut enemy_routes: ResMut<EnemyRoutes>, selected_level: Res<Level>) {
+    enemy_routes.update(&selected_level, &HashSet::new());
+}
+
+fn update_enemy_routes(
+    mut enemies: Query<(&Enemy, &mut TileMovement)>,
+    soldiers: Query<(&Soldier, &TilePosition)>,
+    added_soldiers: Query<(), Added<Soldier>>,
+    mut removed_soldiers: RemovedComponents<Soldier>,
+    mut enemy_routes: ResMut<EnemyRoutes>,
+    selected_level: Res<Level>,
+) {
+    let soldiers_removed = removed_soldiers.read().count() > 0;
+
+    if selected_level.is_maze() == false {
+        return;
+    }
+    if added_soldiers.is_empty() == true && soldiers_removed == false {
+        return;
+    }
+
+    let blocked_tiles = get_blocked_tiles(soldiers.iter());
+
+    enemy_routes.update(&selected_level, &blocked_tiles);
+
+    for (enemy, mut enemy_movement) in enemies.iter_mut() {
+        if enemy.get_domain() == EnemyDomain::Air || enemy_movement.get_progress() >= 1.0 {
+            continue;
+        }
+        let Some(path_start) = enemy_movement.get_path().first().copied() else {
+            continue;
+        };
+
+        let started = enemy_movement.get_progress() > 0.0;
+        let start_position = if started == true {
+            enemy_movement.get_position()
+        } else {
+            path_start
+        };
+
+        let Some(route) = find_route(
+            &selected_level,
+            start_position.round().as_ivec2(),
+            selected_level.get_exits(),
+            enemy.get_domain(),
+            &blocked_tiles,
+        ) else {
+            continue;
+        };
+
+        let enemy_path = if started == true {
+            std::iter::once(start_position).chain(route).collect()
+        } else {
+            route
+        };
+
+        enemy_movement.set_path(enemy_path);
+    }
+}
+
+pub fn get_blocked_tiles<'a>(
+    soldiers: impl Iterator<Item = (&'a Soldier, &'a TilePosition)>,
+) -> HashSet<IVec2> {
+    soldiers
+        .filter(|(soldier, _)| soldier.blocks_route() == true)
+        .map(|(_, soldier_tile_position)| soldier_tile_position.as_vec2().round().as_ivec2())
+        .collect()
+}
+
+fn get_route_color(domain: EnemyDomain) -> Vec3 {
+    match domain {
+        EnemyDomain::Ground => Vec3::new(1.0, 0.5, 0.0),
+        EnemyDomain::Air => Vec3::new(0.5, 1.0, 0.0),
+        EnemyDomain::Naval => Vec3::new(0.0, 0.5, 1.0),
+    }
+}
+
+pub fn find_route(
+    level: &Level,
+    start: IVec2,
+    goals: &[IVec2],
+    domain: EnemyDomain,
+    blocked_tiles: &HashSet<IVec2>,
+) -> Option<Vec<Vec2>> {
+    let heuristic = |position: IVec2| -> i32 {
+        goals
+            .iter()
+            .map(|goal| (goal - position).abs().element_sum())
+            .min()
+            .unwrap_or(0)
+    };
+
+    if domain == EnemyDomain::Air {
+        let goal = goals
+            .iter()
+            .min_by_key(|goal| (*goal - start).abs().element_sum())?;
+        return Some(vec![start.as_vec2(), goal.as_vec2()]);
+    }
+
+    let map_size = level.get_map_size().as_ivec2();
+    let is_traversable = |position: IVec2| -> bool {
+        if position.cmplt(IVec2::ZERO).any() || position.cmpge(map_size).any() {
+            return false;
+        }
+        let tile_variant = level
+            .get_tile(position.x as u32, position.y as u32)
+            .get_variant();
+
+        tile_variant.is_traversable_by(domain)
+            || (level.is_maze() == true
+                && domain == EnemyDomain::Ground
+                && tile_variant.is_open_ground() == true)
+    };
+
+    if is_traversable(start) == false {
+        return None;
+    }
+
+    let mut open_set = BinaryHeap::from([Reverse((heuristic(start), 0, start.to_array()))]);
+    let mut came_from: HashMap<IVec2, IVec2> = HashMap::new();
+    let mut costs: HashMap<IVec2, i32> = HashMap::from([(start, 0)]);
+
+    while let Some(Reverse((_, cost, position))) = open_set.pop() {
+        let position = IVec2::from_array(position);
+
+        if goals.contains(&position) {
+            let mut tiles = vec![position];
+            while let Some(previous) = came_from.get(tiles.last().unwrap()) {
+                tiles.push(*previous);
+            }
+            tiles.reverse();
+
+            return Some(simplify_route(&tiles));
+        }
+        if cost > costs.get(&position).copied().unwrap_or(i32::MAX) {
+            continue;
+        }
+
+        for offset in [IVec2::X, IVec2::NEG_X, IVec2::Y, IVec2::NEG_Y] {
+            let neighbour = position + offset;
+            if is_traversable(neighbour) == false || blocked_tiles.contains(&neighbour) {
+                continue;
+            }
+
+            let neighbour_cost = cost + 1;
+            if neighbour_cost < costs.get(&neighbour).copied().unwrap_or(i32::MAX) {
+                costs.insert(neighbour, neighbour_cost);
+                came_from.insert(neighbour, position);
+                open_set.push(Reverse((
+                    neighbour_cost + heuristic(neighbour),
+                    neighbour_cost,
+                    neighbour.to_array(),
+                )));
+            }
+        }
+    }
+
+    None
+}
+
+fn simplify_route(tiles: &[IVec2]) -> Vec<Vec2> {
+    let mut points: Vec<IVec2> = Vec::new();
+
+    for tile in tiles {
+        if points.len() >= 2 {
+            let last = points[points.len() - 1];
+            let previous = points[points.len() - 2];
+            if (last - previous).signum() == (*tile - last).signum() {
+                points.pop();
+            }
+        }
+        points.push(*tile);
+    }
+
+    points.iter().map(|point| point.as_vec2()).collect()
+}
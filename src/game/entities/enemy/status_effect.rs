@@ -0,0 +1,257 @@
+use std::{mem::discriminant, time::Duration};
+
+use bevy::prelude::*;
+
+use crate::game::{
+    GameState,
+    entities::{
+        enemy::{Enemy, boss::EnemyBoss, health::EnemyHealth},
+        soldier::{Soldier, projectile::DamageType},
+        tile::movement::TileMovement,
+    },
+    speed::GameSpeed,
+};
+
+const BURN_TICK_INTERVAL_SECS: f32 = 0.5;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum EnemyStatusEffectVariant {
+    Slow {
+        speed_multiplier: f32,
+    },
+    Burn {
+        damage_per_tick: u32,
+        max_stacks: u32,
+    },
+    Stun,
+}
+
+impl EnemyStatusEffectVariant {
+    pub fn get_tint(&self) -> Color {
+        match self {
+            EnemyStatusEffectVariant::Slow { .. } => Color::srgb(0.5, 0.75, 1.0),
+            EnemyStatusEffectVariant::Burn { .. } => Color::srgb(1.0, 0.6, 0.2),
+            EnemyStatusEffectVariant::Stun => Color::srgb(1.0, 1.0, 0.4),
+        }
+    }
+    fn get_tint_priority(&self) -> usize {
+        match self {
+            EnemyStatusEffectVariant::Slow { .. } => 0,
+            EnemyStatusEffectVariant::Burn { .. } => 1,
+            EnemyStatusEffectVariant::Stun => 2,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct EnemyStatusEffect {
+    variant: EnemyStatusEffectVariant,
+    duration_secs: f32,
+    source: Option<Entity>,
+}
+
+impl EnemyStatusEffect {
+    pub const fn new(variant: EnemyStatusEffectVariant, duration_secs: f32) -> Self {
+        Self {
+            variant,
+            duration_secs,
+            source: None,
+        }
+    }
+    pub fn with_source(mut self, source: Entity) -> Self {
+        self.source = Some(source);
+        self
+    }
+    pub fn get_variant(&self) -> EnemyStatusEffectVariant {
+        self.variant
+    }
+    pub fn get_duration(&self) -> Duration {
+        Duration::from_secs_f32(self.duration_secs)
+    }
+    pub fn get_source(&self) -> Option<Entity> {
+        self.source
+    }
+}
+
+#[derive(Clone)]
+struct ActiveEnemyStatusEffect {
+    variant: EnemyStatusEffectVariant,
+    stacks: u32,
+    remaining: Duration,
+    tick_elapsed: Duration,
+    source: Option<Entity>,
+}
+
+#[derive(Component, Clone, Default)]
+pub struct EnemyStatusEffects {
+    effects: Vec<ActiveEnemyStatusEffect>,
+}
+
+impl EnemyStatusEffects {
+    pub fn apply(&mut self, status_effect: EnemyStatusEffect) {
+        let variant = status_effect.get_variant();
+        let duration = status_effect.get_duration();
+
+        let Some(active_effect) = self
+            .effects
+            .iter_mut()
+            .find(|effect| discriminant(&effect.variant) == discriminant(&variant))
+        else {
+            self.effects.push(ActiveEnemyStatusEffect {
+                variant,
+                stacks: 1,
+                remaining: duration,
+                tick_elapsed: Duration::ZERO,
+                source: status_effect.get_source(),
+            });
+            return;
+        };
+
+        match (active_effect.variant, variant) {
+            (
+                EnemyStatusEffectVariant::Slow {
+                    speed_multiplier: active_speed_multiplier,
+                },
+                EnemyStatusEffectVariant::Slow { speed_multiplier },
+            ) => {
+                if speed_multiplier < active_speed_multiplier {
+                    active_effect.variant = variant;
+                }
+                active_effect.remaining = active_effect.remaining.max(duration);
+            }
+            (
+                EnemyStatusEffectVariant::Burn {
+                    damage_per_tick: active_damage_per_tick,
+                    ..
+                },
+                EnemyStatusEffectVariant::Burn {
+                    damage_per_tick,
+                    max_stacks,
+                },
+            ) => {
+                if damage_per_tick > active_damage_per_tick {
+                    active_effect.variant = variant;
+                }
+                active_effect.stacks = active_effect.stacks.saturating_add(1).min(max_stacks);
+                active_effect.remaining = duration;
+                if status_effect.get_source().is_some() {
+                    active_effect.source = status_effect.get_source();
+                }
+            }
+            _ => {
+                active_effect.remaining = active_effect.remaining.max(duration);
+            }
+        }
+    }
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+    pub fn get_speed_multiplier(&self) -> f32 {
+        self.effects
+            .iter()
+            .map(|effect| match effect.variant {
+                EnemyStatusEffectVariant::Slow { speed_multiplier } => speed_multiplier,
+                EnemyStatusEffectVariant::Stun => 0.0,
+                _ => 1.0,
+            })
+            .fold(1.0, f32::min)
+    }
+    pub fn get_damage_source(&self) -> Option<Entity> {
+        self.effects
+            .iter()
+            .find(|effect| matches!(effect.variant, EnemyStatusEffectVariant::Burn { .. }))
+            .and_then(|effect| effect.source)
+    }
+    pub fn get_tint(&self) -> Option<Color> {
+        self.effects
+            .iter()
+            .max_by_key(|effect| effect.variant.get_tint_priority())
+            .map(|effect| effect.variant.get_tint())
+    }
+    fn update(&mut self, delta_time: Duration) -> u32 {
+        let mut damage = 0;
+
+        for effect in self.effects.iter_mut() {
+            let active_time = delta_time.min(effect.remaining);
+            effect.remaining = effect.remaining.saturating_sub(delta_time);
+
+            if let EnemyStatusEffectVariant::Burn {
+                damage_per_tick, ..
+            } = effect.variant
+            {
+                let tick_interval = Duration::from_secs_f32(BURN_TICK_INTERVAL_SECS);
+                effect.tick_elapsed += active_time;
+                while effect.tick_elapsed >= tick_interval {
+                    effect.tick_elapsed -= tick_interval;
+                    damage += damage_per_tick * effect.stacks;
+                }
+            }
+        }
+
+        self.effects
+            .retain(|effect| effect.remaining > Duration::ZERO);
+
+        damage
+    }
+}
+
+pub struct EnemyStatusEffectsPlugin;
+
+impl Plugin for EnemyStatusEffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            update_enemy_status_effects.run_if(in_state(GameState::InGame)),
+        );
+    }
+}
+
+fn update_enemy_status_effects(
+    mut enemies: Query<(
+        &Enemy,
+        &mut EnemyStatusEffects,
+        &mut EnemyHealth,
+        &mut TileMovement,
+        Option<&EnemyBoss>,
+    )>,
+    mut soldiers: Query<&mut Soldier>,
+    game_speed: Res<GameSpeed>,
+    time: Res<Time>,
+) {
+    for (enemy, mut enemy_status_effects, mut enemy_health, mut enemy_movement, enemy_boss) in
+        enemies.iter_mut()
+    {
+        let base_speed_multiplier =
+            enemy_boss.map_or(1.0, |enemy_boss| enemy_boss.get_speed_multiplier());
+
+        if enemy_status_effects.is_empty() == true {
+            if enemy_movement.get_speed_multiplier() != base_speed_multiplier {
+                enemy_movement.set_speed_multiplier(base_speed_multiplier);
+            }
+            continue;
+        }
+
+        let damage_source = enemy_status_effects.get_damage_source();
+        let damage = enemy.get_config().get_armor().resolve_damage(
+            enemy_status_effects.update(Duration::from_secs_f32(
+                time.delta_secs() * game_speed.as_f32(),
+            )),
+            DamageType::Fire,
+        );
+        if damage > 0 {
+            let previous_health = enemy_health.get_current();
+
+            enemy_health.damage(damage);
+
+            if let Some(mut soldier) =
+                damage_source.and_then(|source| soldiers.get_mut(source).ok())
+            {
+                soldier.record_damage(previous_health, enemy_health.get_current());
+            }
+        }
+
+        enemy_movement.set_speed_multiplier(
+            base_speed_multiplier * enemy_status_effects.get_speed_multiplier(),
+        );
+    }
+}
@@ -0,0 +1,50 @@
+use bevy::prelude::*;
+
+use crate::game::entities::{
+    soldier::{Soldier, config::SoldierBonus},
+    tile::position::TilePosition,
+};
+
+pub struct SoldierAuraPlugin;
+
+impl Plugin for SoldierAuraPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, update_soldier_aura_bonus);
+    }
+}
+
+fn update_soldier_aura_bonus(mut soldiers: Query<(Entity, &mut Soldier, &TilePosition)>) {
+    let auras = soldiers
+        .iter()
+        .filter_map(|(soldier_entity, soldier, soldier_tile_position)| {
+            soldier.get_config().get_attack().get_aura().map(|aura| {
+                (
+                    soldier_entity,
+                    soldier_tile_position.as_vec2(),
+                    soldier.get_config().get_fire_radius(),
+                    aura,
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for (soldier_entity, mut soldier, soldier_tile_position) in soldiers.iter_mut() {
+        let aura_bonus = if soldier.get_config().get_attack().get_aura().is_some() {
+            SoldierBonus::default()
+        } else {
+            auras
+                .iter()
+                .filter(|(aura_entity, aura_position, aura_radius, _)| {
+                    *aura_entity != soldier_entity
+                        && aura_position.distance(soldier_tile_position.as_vec2()) <= *aura_radius
+                })
+                .fold(SoldierBonus::default(), |aura_bonus, (_, _, _, aura)| {
+                    aura_bonus.max(aura)
+                })
+        };
+
+        if soldier.get_aura_bonus() != aura_bonus {
+            soldier.set_aura_bonus(aura_bonus);
+        }
+    }
+}
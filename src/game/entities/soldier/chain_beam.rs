@@ -0,0 +1,114 @@
+use std::time::Duration;
+
+use bevy::{prelude::*, sprite_render::AlphaMode2d};
+
+use crate::game::{
+    GameState, GameTilemap,
+    entities::{tile::position::TilePosition, tilemap::Tilemap},
+    meshes::rounded_rectangle::RoundedRectangle,
+    speed::GameSpeed,
+};
+
+#[derive(Component, Clone)]
+#[require(Transform)]
+pub struct ChainBeam {
+    start: Vec2,
+    end: Vec2,
+    alpha: f32,
+}
+
+impl ChainBeam {
+    pub fn new(start: Vec2, end: Vec2) -> Self {
+        Self {
+            start,
+            end,
+            alpha: 1.0,
+        }
+    }
+    pub fn get_start(&self) -> Vec2 {
+        self.start
+    }
+    pub fn get_end(&self) -> Vec2 {
+        self.end
+    }
+    pub fn get_alpha(&self) -> f32 {
+        self.alpha
+    }
+    pub fn set_alpha(&mut self, alpha: f32) {
+        self.alpha = alpha;
+    }
+}
+
+pub struct ChainBeamPlugin;
+
+impl Plugin for ChainBeamPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PreUpdate, init_chain_beam);
+
+        app.add_systems(
+            Update,
+            update_chain_beam.run_if(in_state(GameState::InGame)),
+        );
+    }
+}
+
+fn init_chain_beam(
+    mut commands: Commands,
+    game_tilemap: Single<&Tilemap, With<GameTilemap>>,
+    chain_beams: Query<(Entity, &ChainBeam), Added<ChainBeam>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    let game_tilemap = game_tilemap.into_inner();
+    for (chain_beam_entity, chain_beam) in chain_beams.iter() {
+        let start =
+            TilePosition::from_vec2(chain_beam.get_start()).get_tilemap_position(game_tilemap);
+        let end = TilePosition::from_vec2(chain_beam.get_end()).get_tilemap_position(game_tilemap);
+        let offset = end - start;
+
+        commands.entity(chain_beam_entity).insert((
+            Mesh2d(meshes.add(RoundedRectangle::new(offset.length(), 2.0, 1.0))),
+            MeshMaterial2d(materials.add(ColorMaterial {
+                color: Color::srgb(0.5, 0.75, 1.0).with_alpha(chain_beam.get_alpha()),
+                alpha_mode: AlphaMode2d::Blend,
+                ..default()
+            })),
+            Transform::from_translation(((start + end) / 2.0).extend(1.5))
+                .with_rotation(Quat::from_rotation_z(offset.y.atan2(offset.x))),
+        ));
+    }
+}
+
+fn update_chain_beam(
+    mut commands: Commands,
+    mut chain_beams: Query<(
+        Entity,
+        &mut ChainBeam,
+        Option<&MeshMaterial2d<ColorMaterial>>,
+    )>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    game_speed: Res<GameSpeed>,
+    time: Res<Time>,
+) {
+    for (chain_beam_entity, mut chain_beam, chain_beam_mesh_material_2d) in chain_beams.iter_mut() {
+        if chain_beam.get_alpha() < 1e-3 {
+            commands.entity(chain_beam_entity).despawn();
+            continue;
+        }
+
+        let current_alpha = chain_beam.get_alpha();
+
+        if let Some(chain_beam_mesh_material_2d) = chain_beam_mesh_material_2d {
+            if let Some(chain_beam_color_material) =
+                materials.get_mut(&chain_beam_mesh_material_2d.0)
+            {
+                chain_beam_color_material.color.set_alpha(current_alpha);
+            }
+        }
+
+        chain_beam.set_alpha(current_alpha.lerp(
+            0.0,
+            time.delta_secs() * game_speed.as_f32() / Duration::from_millis(150).as_secs_f32(),
+        ));
+    }
+}
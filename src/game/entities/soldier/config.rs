@@ -1,9 +1,169 @@
-use std::time::Duration;
+use std::{
+    f32::consts::{FRAC_PI_2, FRAC_PI_3},
+    time::Duration,
+};
 
 use crate::game::{
-    assets::images::entity::SoldierSpriteVariant, entities::soldier::projectile::ProjectileVariant,
+    assets::images::entity::SoldierSpriteVariant,
+    entities::{
+        enemy::{
+            EnemyDomain,
+            status_effect::{EnemyStatusEffect, EnemyStatusEffectVariant},
+        },
+        soldier::projectile::{DamageType, ProjectileKinematics, ProjectileVariant},
+    },
 };
 
+#[derive(Clone, Copy, PartialEq, Default)]
+pub struct SoldierBonus {
+    damage: f32,
+    fire_rate: f32,
+    fire_radius: f32,
+}
+
+impl SoldierBonus {
+    pub fn get_damage(&self) -> f32 {
+        self.damage
+    }
+    pub fn get_fire_rate(&self) -> f32 {
+        self.fire_rate
+    }
+    pub fn get_fire_radius(&self) -> f32 {
+        self.fire_radius
+    }
+    pub fn sum(&self, other: &SoldierBonus) -> SoldierBonus {
+        SoldierBonus {
+            damage: self.damage + other.damage,
+            fire_rate: self.fire_rate + other.fire_rate,
+            fire_radius: self.fire_radius + other.fire_radius,
+        }
+    }
+    pub fn max(&self, other: &SoldierBonus) -> SoldierBonus {
+        SoldierBonus {
+            damage: self.damage.max(other.damage),
+            fire_rate: self.fire_rate.max(other.fire_rate),
+            fire_radius: self.fire_radius.max(other.fire_radius),
+        }
+    }
+    pub fn apply_damage(&self, damage: u32) -> u32 {
+        (damage as f32 * (1.0 + self.damage)).round() as u32
+    }
+    pub fn apply_fire_rate(&self, fire_rate: Duration) -> Duration {
+        fire_rate.div_f32(1.0 + self.fire_rate)
+    }
+    pub fn apply_fire_radius(&self, fire_radius: f32) -> f32 {
+        fire_radius * (1.0 + self.fire_radius)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum SoldierAttack {
+    Projectile(ProjectileVariant),
+    Cone {
+        damage_type: DamageType,
+        angle: f32,
+        status_effect: Option<EnemyStatusEffect>,
+    },
+    Aura(SoldierBonus),
+    Trap {
+        damage_type: DamageType,
+        blast_radius: Option<f32>,
+        charges: u32,
+    },
+}
+
+impl SoldierAttack {
+    pub fn get_projectile_variant(&self) -> Option<ProjectileVariant> {
+        match self {
+            SoldierAttack::Projectile(projectile_variant) => Some(*projectile_variant),
+            _ => None,
+        }
+    }
+    pub fn get_cone_angle(&self) -> Option<f32> {
+        match self {
+            SoldierAttack::Cone { angle, .. } => Some(*angle),
+            _ => None,
+        }
+    }
+    pub fn get_aura(&self) -> Option<SoldierBonus> {
+        match self {
+            SoldierAttack::Aura(aura) => Some(*aura),
+            _ => None,
+        }
+    }
+    pub fn get_trap_charges(&self) -> Option<u32> {
+        match self {
+            SoldierAttack::Trap { charges, .. } => Some(*charges),
+            _ => None,
+        }
+    }
+    pub fn get_damage_type(&self) -> Option<DamageType> {
+        match self {
+            SoldierAttack::Projectile(projectile_variant) => {
+                Some(projectile_variant.get_damage_type())
+            }
+            SoldierAttack::Cone { damage_type, .. } | SoldierAttack::Trap { damage_type, .. } => {
+                Some(*damage_type)
+            }
+            SoldierAttack::Aura(_) => None,
+        }
+    }
+    pub fn is_passive(&self) -> bool {
+        matches!(self, SoldierAttack::Aura(_) | SoldierAttack::Trap { .. })
+    }
+}
+
+pub struct SoldierRankConfig {
+    kills: u32,
+    damage_dealt: u32,
+    bonus: SoldierBonus,
+}
+
+impl SoldierRankConfig {
+    pub fn get_kills(&self) -> u32 {
+        self.kills
+    }
+    pub fn get_damage_dealt(&self) -> u32 {
+        self.damage_dealt
+    }
+    pub fn get_bonus(&self) -> SoldierBonus {
+        self.bonus
+    }
+    pub fn is_reached(&self, kills: u32, damage_dealt: u32) -> bool {
+        kills >= self.kills || damage_dealt >= self.damage_dealt
+    }
+}
+
+pub const SOLDIER_RANKS: [SoldierRankConfig; 3] = [
+    SoldierRankConfig {
+        kills: 10,
+        damage_dealt: 5000,
+        bonus: SoldierBonus {
+            damage: 0.05,
+            fire_rate: 0.0,
+            fire_radius: 0.0,
+        },
+    },
+    SoldierRankConfig {
+        kills: 25,
+        damage_dealt: 15000,
+        bonus: SoldierBonus {
+            damage: 0.1,
+            fire_rate: 0.05,
+            fire_radius: 0.0,
+        },
+    },
+    SoldierRankConfig {
+        kills: 50,
+        damage_dealt: 40000,
+        bonus: SoldierBonus {
+            damage: 0.15,
+            fire_rate: 0.1,
+            fire_radius: 0.05,
+        },
+    },
+];
+
 pub struct SoldierConfig {
     price: u32,
     sell_price: u32,
@@ -11,7 +171,9 @@ pub struct SoldierConfig {
     fire_radius: f32,
     fire_rate_secs: f32,
     sprite_variant: SoldierSpriteVariant,
-    projectile_variant: ProjectileVariant,
+    attack: SoldierAttack,
+    target_domains: &'static [EnemyDomain],
+    upgrades: &'static [usize],
 }
 
 impl SoldierConfig {
@@ -24,6 +186,9 @@ impl SoldierConfig {
     pub fn get_damage(&self) -> u32 {
         self.damage
     }
+    pub fn get_damage_type(&self) -> Option<DamageType> {
+        self.attack.get_damage_type()
+    }
     pub fn get_fire_radius(&self) -> f32 {
         self.fire_radius
     }
@@ -33,8 +198,28 @@ impl SoldierConfig {
     pub fn get_sprite_variant(&self) -> SoldierSpriteVariant {
         self.sprite_variant
     }
-    pub fn get_projectile_variant(&self) -> ProjectileVariant {
-        self.projectile_variant
+    pub fn get_attack(&self) -> SoldierAttack {
+        self.attack
+    }
+    pub fn get_projectile_variant(&self) -> Option<ProjectileVariant> {
+        self.attack.get_projectile_variant()
+    }
+    pub fn get_blast_radius(&self) -> Option<f32> {
+        match self.attack {
+            SoldierAttack::Trap { blast_radius, .. } => blast_radius,
+            _ => self
+                .get_projectile_variant()
+                .and_then(|projectile_variant| projectile_variant.get_blast_radius()),
+        }
+    }
+    pub fn get_target_domains(&self) -> &'static [EnemyDomain] {
+        self.target_domains
+    }
+    pub fn can_target(&self, domain: EnemyDomain) -> bool {
+        self.target_domains.contains(&domain)
+    }
+    pub fn get_upgrades(&self) -> &'static [usize] {
+        self.upgrades
     }
 }
 
@@ -46,7 +231,13 @@ pub const SOLDIER_LEVELS: [SoldierConfig; 3] = [
         fire_radius: 2.5,
         fire_rate_secs: 0.5,
         sprite_variant: SoldierSpriteVariant::SoldierGray,
-        projectile_variant: ProjectileVariant::Bullet,
+        attack: SoldierAttack::Projectile(ProjectileVariant::Bullet {
+            kinematics: ProjectileKinematics::Ballistic { speed: 30.0 },
+            damage_type: DamageType::Kinetic,
+            status_effect: None,
+        }),
+        target_domains: &[EnemyDomain::Ground, EnemyDomain::Air],
+        upgrades: &[1],
     },
     SoldierConfig {
         price: 100,
@@ -55,7 +246,13 @@ pub const SOLDIER_LEVELS: [SoldierConfig; 3] = [
         fire_radius: 3.0,
         fire_rate_secs: 0.5,
         sprite_variant: SoldierSpriteVariant::SoldierYellow,
-        projectile_variant: ProjectileVariant::Bullet,
+        attack: SoldierAttack::Projectile(ProjectileVariant::Bullet {
+            kinematics: ProjectileKinematics::Ballistic { speed: 30.0 },
+            damage_type: DamageType::Kinetic,
+            status_effect: None,
+        }),
+        target_domains: &[EnemyDomain::Ground, EnemyDomain::Air],
+        upgrades: &[2],
     },
     SoldierConfig {
         price: 200,
@@ -64,11 +261,22 @@ pub const SOLDIER_LEVELS: [SoldierConfig; 3] = [
         fire_radius: 3.5,
         fire_rate_secs: 0.5,
         sprite_variant: SoldierSpriteVariant::SoldierRed,
-        projectile_variant: ProjectileVariant::Bullet,
+        attack: SoldierAttack::Projectile(ProjectileVariant::Bullet {
+            kinematics: ProjectileKinematics::Ballistic { speed: 30.0 },
+            damage_type: DamageType::Kinetic,
+            status_effect: Some(EnemyStatusEffect::new(
+                EnemyStatusEffectVariant::Slow {
+                    speed_multiplier: 0.7,
+                },
+                1.5,
+            )),
+        }),
+        target_domains: &[EnemyDomain::Ground, EnemyDomain::Air],
+        upgrades: &[],
     },
 ];
 
-pub const ROCKET_LAUNCHER_LEVELS: [SoldierConfig; 3] = [
+pub const ROCKET_LAUNCHER_LEVELS: [SoldierConfig; 4] = [
     SoldierConfig {
         price: 200,
         sell_price: 140,
@@ -76,7 +284,14 @@ pub const ROCKET_LAUNCHER_LEVELS: [SoldierConfig; 3] = [
         fire_radius: 3.5,
         fire_rate_secs: 1.5,
         sprite_variant: SoldierSpriteVariant::RocketLauncherGray,
-        projectile_variant: ProjectileVariant::Rocket { blast_radius: 1.0 },
+        attack: SoldierAttack::Projectile(ProjectileVariant::Rocket {
+            kinematics: ProjectileKinematics::Homing { speed: 15.0 },
+            damage_type: DamageType::Explosive,
+            blast_radius: 1.0,
+            status_effect: None,
+        }),
+        target_domains: &[EnemyDomain::Ground, EnemyDomain::Naval],
+        upgrades: &[1],
     },
     SoldierConfig {
         price: 150,
@@ -85,7 +300,14 @@ pub const ROCKET_LAUNCHER_LEVELS: [SoldierConfig; 3] = [
         fire_radius: 4.0,
         fire_rate_secs: 1.5,
         sprite_variant: SoldierSpriteVariant::RocketLauncherYellow,
-        projectile_variant: ProjectileVariant::Rocket { blast_radius: 1.5 },
+        attack: SoldierAttack::Projectile(ProjectileVariant::Rocket {
+            kinematics: ProjectileKinematics::Homing { speed: 15.0 },
+            damage_type: DamageType::Explosive,
+            blast_radius: 1.5,
+            status_effect: None,
+        }),
+        target_domains: &[EnemyDomain::Ground, EnemyDomain::Naval],
+        upgrades: &[2, 3],
     },
     SoldierConfig {
         price: 200,
@@ -94,7 +316,36 @@ pub const ROCKET_LAUNCHER_LEVELS: [SoldierConfig; 3] = [
         fire_radius: 4.0,
         fire_rate_secs: 1.5,
         sprite_variant: SoldierSpriteVariant::RocketLauncherRed,
-        projectile_variant: ProjectileVariant::Rocket { blast_radius: 2.0 },
+        attack: SoldierAttack::Projectile(ProjectileVariant::Rocket {
+            kinematics: ProjectileKinematics::Homing { speed: 15.0 },
+            damage_type: DamageType::Explosive,
+            blast_radius: 2.0,
+            status_effect: Some(EnemyStatusEffect::new(
+                EnemyStatusEffectVariant::Burn {
+                    damage_per_tick: 20,
+                    max_stacks: 3,
+                },
+                3.0,
+            )),
+        }),
+        target_domains: &[EnemyDomain::Ground, EnemyDomain::Naval],
+        upgrades: &[],
+    },
+    SoldierConfig {
+        price: 200,
+        sell_price: 385,
+        damage: 300,
+        fire_radius: 4.0,
+        fire_rate_secs: 0.75,
+        sprite_variant: SoldierSpriteVariant::RocketLauncherBlue,
+        attack: SoldierAttack::Projectile(ProjectileVariant::Rocket {
+            kinematics: ProjectileKinematics::Homing { speed: 15.0 },
+            damage_type: DamageType::Explosive,
+            blast_radius: 1.5,
+            status_effect: None,
+        }),
+        target_domains: &[EnemyDomain::Ground, EnemyDomain::Naval],
+        upgrades: &[],
     },
 ];
 
@@ -106,7 +357,15 @@ pub const SNIPER_LEVELS: [SoldierConfig; 2] = [
         fire_radius: 4.0,
         fire_rate_secs: 2.0,
         sprite_variant: SoldierSpriteVariant::SoldierGreen,
-        projectile_variant: ProjectileVariant::Bullet,
+        attack: SoldierAttack::Projectile(ProjectileVariant::Piercing {
+            kinematics: ProjectileKinematics::Ballistic { speed: 40.0 },
+            damage_type: DamageType::Piercing,
+            hit_width: 0.5,
+            max_pierce: 3,
+            status_effect: None,
+        }),
+        target_domains: &[EnemyDomain::Ground, EnemyDomain::Air],
+        upgrades: &[1],
     },
     SoldierConfig {
         price: 200,
@@ -115,6 +374,337 @@ pub const SNIPER_LEVELS: [SoldierConfig; 2] = [
         fire_radius: 5.0,
         fire_rate_secs: 2.0,
         sprite_variant: SoldierSpriteVariant::SoldierBlue,
-        projectile_variant: ProjectileVariant::Bullet,
+        attack: SoldierAttack::Projectile(ProjectileVariant::Piercing {
+            kinematics: ProjectileKinematics::Ballistic { speed: 40.0 },
+            damage_type: DamageType::Piercing,
+            hit_width: 0.75,
+            max_pierce: 5,
+            status_effect: Some(EnemyStatusEffect::new(EnemyStatusEffectVariant::Stun, 0.5)),
+        }),
+        target_domains: &[EnemyDomain::Ground, EnemyDomain::Air],
+        upgrades: &[],
+    },
+];
+
+pub const FLAMETHROWER_LEVELS: [SoldierConfig; 3] = [
+    SoldierConfig {
+        price: 200,
+        sell_price: 140,
+        damage: 30,
+        fire_radius: 1.5,
+        fire_rate_secs: 0.25,
+        sprite_variant: SoldierSpriteVariant::FlamethrowerGray,
+        attack: SoldierAttack::Cone {
+            damage_type: DamageType::Fire,
+            angle: FRAC_PI_3,
+            status_effect: Some(EnemyStatusEffect::new(
+                EnemyStatusEffectVariant::Burn {
+                    damage_per_tick: 10,
+                    max_stacks: 3,
+                },
+                2.0,
+            )),
+        },
+        target_domains: &[EnemyDomain::Ground, EnemyDomain::Naval],
+        upgrades: &[1],
+    },
+    SoldierConfig {
+        price: 150,
+        sell_price: 245,
+        damage: 45,
+        fire_radius: 1.75,
+        fire_rate_secs: 0.25,
+        sprite_variant: SoldierSpriteVariant::FlamethrowerYellow,
+        attack: SoldierAttack::Cone {
+            damage_type: DamageType::Fire,
+            angle: FRAC_PI_3,
+            status_effect: Some(EnemyStatusEffect::new(
+                EnemyStatusEffectVariant::Burn {
+                    damage_per_tick: 15,
+                    max_stacks: 3,
+                },
+                2.0,
+            )),
+        },
+        target_domains: &[EnemyDomain::Ground, EnemyDomain::Naval],
+        upgrades: &[2],
+    },
+    SoldierConfig {
+        price: 200,
+        sell_price: 385,
+        damage: 60,
+        fire_radius: 2.0,
+        fire_rate_secs: 0.25,
+        sprite_variant: SoldierSpriteVariant::FlamethrowerRed,
+        attack: SoldierAttack::Cone {
+            damage_type: DamageType::Fire,
+            angle: FRAC_PI_2,
+            status_effect: Some(EnemyStatusEffect::new(
+                EnemyStatusEffectVariant::Burn {
+                    damage_per_tick: 20,
+                    max_stacks: 3,
+                },
+                2.0,
+            )),
+        },
+        target_domains: &[EnemyDomain::Ground, EnemyDomain::Naval],
+        upgrades: &[],
+    },
+];
+
+pub const SUPPORT_LEVELS: [SoldierConfig; 3] = [
+    SoldierConfig {
+        price: 250,
+        sell_price: 175,
+        damage: 0,
+        fire_radius: 1.5,
+        fire_rate_secs: 1.0,
+        sprite_variant: SoldierSpriteVariant::SupportGray,
+        attack: SoldierAttack::Aura(SoldierBonus {
+            damage: 0.15,
+            fire_rate: 0.0,
+            fire_radius: 0.0,
+        }),
+        target_domains: &[],
+        upgrades: &[1],
+    },
+    SoldierConfig {
+        price: 200,
+        sell_price: 315,
+        damage: 0,
+        fire_radius: 2.0,
+        fire_rate_secs: 1.0,
+        sprite_variant: SoldierSpriteVariant::SupportYellow,
+        attack: SoldierAttack::Aura(SoldierBonus {
+            damage: 0.2,
+            fire_rate: 0.1,
+            fire_radius: 0.0,
+        }),
+        target_domains: &[],
+        upgrades: &[2],
+    },
+    SoldierConfig {
+        price: 250,
+        sell_price: 490,
+        damage: 0,
+        fire_radius: 2.5,
+        fire_rate_secs: 1.0,
+        sprite_variant: SoldierSpriteVariant::SupportRed,
+        attack: SoldierAttack::Aura(SoldierBonus {
+            damage: 0.25,
+            fire_rate: 0.2,
+            fire_radius: 0.1,
+        }),
+        target_domains: &[],
+        upgrades: &[],
+    },
+];
+
+pub const MINE_LEVELS: [SoldierConfig; 3] = [
+    SoldierConfig {
+        price: 100,
+        sell_price: 70,
+        damage: 400,
+        fire_radius: 0.5,
+        fire_rate_secs: 0.5,
+        sprite_variant: SoldierSpriteVariant::MineGray,
+        attack: SoldierAttack::Trap {
+            damage_type: DamageType::Explosive,
+            blast_radius: Some(1.0),
+            charges: 1,
+        },
+        target_domains: &[EnemyDomain::Ground],
+        upgrades: &[1],
+    },
+    SoldierConfig {
+        price: 75,
+        sell_price: 122,
+        damage: 500,
+        fire_radius: 0.5,
+        fire_rate_secs: 0.5,
+        sprite_variant: SoldierSpriteVariant::MineYellow,
+        attack: SoldierAttack::Trap {
+            damage_type: DamageType::Explosive,
+            blast_radius: Some(1.25),
+            charges: 2,
+        },
+        target_domains: &[EnemyDomain::Ground],
+        upgrades: &[2],
+    },
+    SoldierConfig {
+        price: 100,
+        sell_price: 192,
+        damage: 600,
+        fire_radius: 0.5,
+        fire_rate_secs: 0.5,
+        sprite_variant: SoldierSpriteVariant::MineRed,
+        attack: SoldierAttack::Trap {
+            damage_type: DamageType::Explosive,
+            blast_radius: Some(1.5),
+            charges: 3,
+        },
+        target_domains: &[EnemyDomain::Ground],
+        upgrades: &[],
+    },
+];
+
+pub const SPIKES_LEVELS: [SoldierConfig; 3] = [
+    SoldierConfig {
+        price: 75,
+        sell_price: 52,
+        damage: 100,
+        fire_radius: 0.5,
+        fire_rate_secs: 0.5,
+        sprite_variant: SoldierSpriteVariant::SpikesGray,
+        attack: SoldierAttack::Trap {
+            damage_type: DamageType::Piercing,
+            blast_radius: None,
+            charges: 8,
+        },
+        target_domains: &[EnemyDomain::Ground],
+        upgrades: &[1],
+    },
+    SoldierConfig {
+        price: 50,
+        sell_price: 87,
+        damage: 150,
+        fire_radius: 0.5,
+        fire_rate_secs: 0.5,
+        sprite_variant: SoldierSpriteVariant::SpikesYellow,
+        attack: SoldierAttack::Trap {
+            damage_type: DamageType::Piercing,
+            blast_radius: None,
+            charges: 12,
+        },
+        target_domains: &[EnemyDomain::Ground],
+        upgrades: &[2],
+    },
+    SoldierConfig {
+        price: 75,
+        sell_price: 140,
+        damage: 200,
+        fire_radius: 0.5,
+        fire_rate_secs: 0.5,
+        sprite_variant: SoldierSpriteVariant::SpikesRed,
+        attack: SoldierAttack::Trap {
+            damage_type: DamageType::Piercing,
+            blast_radius: None,
+            charges: 16,
+        },
+        target_domains: &[EnemyDomain::Ground],
+        upgrades: &[],
+    },
+];
+
+pub const GUNBOAT_LEVELS: [SoldierConfig; 3] = [
+    SoldierConfig {
+        price: 250,
+        sell_price: 175,
+        damage: 250,
+        fire_radius: 3.0,
+        fire_rate_secs: 1.25,
+        sprite_variant: SoldierSpriteVariant::GunboatGray,
+        attack: SoldierAttack::Projectile(ProjectileVariant::Rocket {
+            kinematics: ProjectileKinematics::Homing { speed: 15.0 },
+            damage_type: DamageType::Explosive,
+            blast_radius: 0.75,
+            status_effect: None,
+        }),
+        target_domains: &[EnemyDomain::Naval],
+        upgrades: &[1],
+    },
+    SoldierConfig {
+        price: 200,
+        sell_price: 315,
+        damage: 400,
+        fire_radius: 3.5,
+        fire_rate_secs: 1.0,
+        sprite_variant: SoldierSpriteVariant::GunboatYellow,
+        attack: SoldierAttack::Projectile(ProjectileVariant::Rocket {
+            kinematics: ProjectileKinematics::Homing { speed: 15.0 },
+            damage_type: DamageType::Explosive,
+            blast_radius: 1.0,
+            status_effect: None,
+        }),
+        target_domains: &[EnemyDomain::Naval],
+        upgrades: &[2],
+    },
+    SoldierConfig {
+        price: 250,
+        sell_price: 490,
+        damage: 600,
+        fire_radius: 4.0,
+        fire_rate_secs: 1.0,
+        sprite_variant: SoldierSpriteVariant::GunboatRed,
+        attack: SoldierAttack::Projectile(ProjectileVariant::Rocket {
+            kinematics: ProjectileKinematics::Homing { speed: 15.0 },
+            damage_type: DamageType::Explosive,
+            blast_radius: 1.25,
+            status_effect: Some(EnemyStatusEffect::new(
+                EnemyStatusEffectVariant::Slow {
+                    speed_multiplier: 0.5,
+                },
+                2.0,
+            )),
+        }),
+        target_domains: &[EnemyDomain::Naval],
+        upgrades: &[],
+    },
+];
+
+pub const TESLA_LEVELS: [SoldierConfig; 3] = [
+    SoldierConfig {
+        price: 300,
+        sell_price: 210,
+        damage: 120,
+        fire_radius: 2.5,
+        fire_rate_secs: 1.0,
+        sprite_variant: SoldierSpriteVariant::TeslaGray,
+        attack: SoldierAttack::Projectile(ProjectileVariant::Chain {
+            kinematics: ProjectileKinematics::Homing { speed: 40.0 },
+            damage_type: DamageType::Kinetic,
+            jumps: 2,
+            jump_radius: 1.5,
+            damage_falloff: 0.25,
+            status_effect: None,
+        }),
+        target_domains: &[EnemyDomain::Ground, EnemyDomain::Air, EnemyDomain::Naval],
+        upgrades: &[1],
+    },
+    SoldierConfig {
+        price: 250,
+        sell_price: 385,
+        damage: 160,
+        fire_radius: 3.0,
+        fire_rate_secs: 0.9,
+        sprite_variant: SoldierSpriteVariant::TeslaYellow,
+        attack: SoldierAttack::Projectile(ProjectileVariant::Chain {
+            kinematics: ProjectileKinematics::Homing { speed: 40.0 },
+            damage_type: DamageType::Kinetic,
+            jumps: 3,
+            jump_radius: 1.75,
+            damage_falloff: 0.2,
+            status_effect: None,
+        }),
+        target_domains: &[EnemyDomain::Ground, EnemyDomain::Air, EnemyDomain::Naval],
+        upgrades: &[2],
+    },
+    SoldierConfig {
+        price: 300,
+        sell_price: 595,
+        damage: 220,
+        fire_radius: 3.5,
+        fire_rate_secs: 0.8,
+        sprite_variant: SoldierSpriteVariant::TeslaRed,
+        attack: SoldierAttack::Projectile(ProjectileVariant::Chain {
+            kinematics: ProjectileKinematics::Homing { speed: 40.0 },
+            damage_type: DamageType::Kinetic,
+            jumps: 4,
+            jump_radius: 2.0,
+            damage_falloff: 0.15,
+            status_effect: Some(EnemyStatusEffect::new(EnemyStatusEffectVariant::Stun, 0.25)),
+        }),
+        target_domains: &[EnemyDomain::Ground, EnemyDomain::Air, EnemyDomain::Naval],
+        upgrades: &[],
     },
 ];
@@ -0,0 +1,164 @@
+use std::time::Duration;
+
+use bevy::{prelude::*, sprite_render::AlphaMode2d};
+
+use crate::game::{
+    GameState, GameTilemap,
+    entities::{soldier::Soldier, tile::position::TilePosition, tilemap::Tilemap},
+    input::SelectedTile,
+    meshes::annular_segment::AnnularSegment,
+};
+
+#[derive(Component, Clone)]
+#[require(Transform)]
+pub struct FireCone {
+    visible: bool,
+    soldier_entity: Entity,
+    aim_direction: Vec2,
+    radius: f32,
+}
+
+impl FireCone {
+    pub fn new(soldier_entity: Entity) -> Self {
+        Self {
+            visible: false,
+            soldier_entity,
+            aim_direction: Vec2::ZERO,
+            radius: 0.0,
+        }
+    }
+    pub fn get_soldier_entity(&self) -> Entity {
+        self.soldier_entity
+    }
+    pub fn get_visible(&self) -> bool {
+        self.visible
+    }
+    pub fn set_visible(&mut self, value: bool) {
+        self.visible = value;
+    }
+    pub fn get_aim_direction(&self) -> Vec2 {
+        self.aim_direction
+    }
+    pub fn get_radius(&self) -> f32 {
+        self.radius
+    }
+    pub fn set_shape(&mut self, aim_direction: Vec2, radius: f32) {
+        self.aim_direction = aim_direction;
+        self.radius = radius;
+    }
+}
+
+pub struct FireConePlugin;
+
+impl Plugin for FireConePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PreUpdate, init_fire_cone);
+        app.add_systems(PostUpdate, despawn_fire_cone);
+
+        app.add_systems(Update, update_fire_cone.run_if(in_state(GameState::InGame)));
+        app.add_systems(
+            PostUpdate,
+            update_fire_cone_alpha.run_if(in_state(GameState::InGame)),
+        );
+    }
+}
+
+fn init_fire_cone(
+    mut commands: Commands,
+    fire_cones: Query<Entity, Added<FireCone>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    for fire_cone_entity in fire_cones.iter() {
+        commands.entity(fire_cone_entity).insert((
+            Mesh2d(meshes.add(AnnularSegment::new(0.0, 1.0))),
+            MeshMaterial2d(materials.add(ColorMaterial {
+                color: Color::srgb(1.0, 0.5, 0.0).with_alpha(0.0),
+                alpha_mode: AlphaMode2d::Blend,
+                ..default()
+            })),
+        ));
+    }
+}
+
+fn despawn_fire_cone(
+    mut commands: Commands,
+    fire_cones: Query<(Entity, &FireCone)>,
+    mut removed_soldiers: RemovedComponents<Soldier>,
+) {
+    for removed_soldier_entity in removed_soldiers.read() {
+        for (fire_cone_entity, fire_cone) in fire_cones.iter() {
+            if fire_cone.get_soldier_entity() == removed_soldier_entity {
+                commands.entity(fire_cone_entity).despawn();
+            }
+        }
+    }
+}
+
+fn update_fire_cone(
+    game_tilemap: Single<&Tilemap, With<GameTilemap>>,
+    soldiers: Query<(&Soldier, &TilePosition, &Transform)>,
+    mut fire_cones: Query<(&mut FireCone, &Mesh2d, &mut Transform), Without<Soldier>>,
+    selected_tile: Res<SelectedTile>,
+    mut meshes: ResMut<Assets<Mesh>>,
+) {
+    for (mut fire_cone, fire_cone_mesh_2d, mut fire_cone_transform) in fire_cones.iter_mut() {
+        let Ok((soldier, soldier_tile_position, soldier_transform)) =
+            soldiers.get(fire_cone.get_soldier_entity())
+        else {
+            continue;
+        };
+
+        let Some(angle) = soldier.get_config().get_attack().get_cone_angle() else {
+            fire_cone.set_visible(false);
+            continue;
+        };
+
+        let aim_direction = soldier.get_aim_direction();
+        let radius = soldier.get_fire_radius();
+
+        if fire_cone.get_aim_direction() != aim_direction || fire_cone.get_radius() != radius {
+            if let Some(fire_cone_mesh) = meshes.get_mut(&fire_cone_mesh_2d.0) {
+                AnnularSegment::update(
+                    fire_cone_mesh,
+                    0.0,
+                    radius * game_tilemap.get_tile_size() as f32,
+                    (-aim_direction.y).atan2(aim_direction.x) - angle / 2.0,
+                    angle,
+                );
+                fire_cone.set_shape(aim_direction, radius);
+            }
+        }
+
+        fire_cone_transform.translation = soldier_transform.translation.with_z(1.0);
+
+        fire_cone
+            .set_visible(soldier_tile_position.as_vec2() == selected_tile.tile_position.as_vec2());
+    }
+}
+
+fn update_fire_cone_alpha(
+    fire_cones: Query<(&FireCone, &MeshMaterial2d<ColorMaterial>)>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    time: Res<Time>,
+) {
+    for (fire_cone, fire_cone_mesh_material_2d) in fire_cones.iter() {
+        if let Some(fire_cone_color_material) = materials.get_mut(&fire_cone_mesh_material_2d.0) {
+            let target_alpha = if fire_cone.get_visible() { 0.25 } else { 0.0 };
+            let current_alpha = fire_cone_color_material.color.alpha();
+
+            if current_alpha == target_alpha {
+                continue;
+            }
+            if (current_alpha - target_alpha).abs() > 1e-3 {
+                let new_alpha = current_alpha.lerp(
+                    target_alpha,
+                    (time.delta_secs() / Duration::from_millis(50).as_secs_f32()).clamp(0.0, 1.0),
+                );
+                fire_cone_color_material.color.set_alpha(new_alpha);
+            } else {
+                fire_cone_color_material.color.set_alpha(target_alpha);
+            }
+        }
+    }
+}
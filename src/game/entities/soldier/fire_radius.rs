@@ -3,8 +3,14 @@ use std::time::Duration;
 use bevy::{prelude::*, sprite_render::AlphaMode2d};
 
 use crate::game::{
-    entities::{soldier::Soldier, tile::position::TilePosition, tilemap::Tilemap},
+    assets::levels::Level,
+    entities::{
+        soldier::Soldier,
+        tile::position::TilePosition,
+        tilemap::{Tilemap, tile::TilemapTile},
+    },
     input::SelectedTile,
+    meshes::tile_grid::TileGrid,
     {GameState, GameTilemap},
 };
 
@@ -33,6 +39,9 @@ impl FireRadius {
     }
 }
 
+#[derive(Component)]
+struct FireRadiusShadow;
+
 pub struct FireRadiusPlugin;
 
 impl Plugin for FireRadiusPlugin {
@@ -54,9 +63,11 @@ impl Plugin for FireRadiusPlugin {
 fn init_fire_radius(
     mut commands: Commands,
     game_tilemap: Single<&Tilemap, With<GameTilemap>>,
+    tiles: Query<&TilemapTile>,
     mut fire_radii: Query<(Entity, &mut FireRadius, &mut Transform), Added<FireRadius>>,
     soldiers: Query<(&Soldier, &TilePosition, &Transform), Without<FireRadius>>,
     selected_tile: Res<SelectedTile>,
+    level: Res<Level>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
 ) {
@@ -80,18 +91,44 @@ fn init_fire_radius(
                     alpha_mode: AlphaMode2d::Blend,
                     ..default()
                 })),
-                children![(
-                    Mesh2d(meshes.add(Circle::new(inner_radius))),
-                    MeshMaterial2d(materials.add(ColorMaterial {
-                        color: Color::srgb(0.25, 0.25, 0.5).with_alpha(if fire_radius_visible {
-                            0.25
-                        } else {
-                            0.0
-                        }),
-                        alpha_mode: AlphaMode2d::Blend,
-                        ..default()
-                    })),
-                )],
+                children![
+                    (
+                        Mesh2d(meshes.add(Circle::new(inner_radius))),
+                        MeshMaterial2d(materials.add(ColorMaterial {
+                            color:
+                                Color::srgb(0.25, 0.25, 0.5).with_alpha(if fire_radius_visible {
+                                    0.25
+                                } else {
+                                    0.0
+                                }),
+                            alpha_mode: AlphaMode2d::Blend,
+                            ..default()
+                        })),
+                    ),
+                    (
+                        FireRadiusShadow,
+                        Mesh2d(meshes.add(TileGrid::new(
+                            &get_occluded_tiles(
+                                &game_tilemap,
+                                &tiles,
+                                &level,
+                                soldier_tile_position.as_vec2(),
+                                soldier.get_fire_radius(),
+                            ),
+                            game_tilemap.get_tile_size() as f32,
+                        ))),
+                        MeshMaterial2d(materials.add(ColorMaterial {
+                            color: Color::srgb(0.0, 0.0, 0.1).with_alpha(if fire_radius_visible {
+                                0.25
+                            } else {
+                                0.0
+                            }),
+                            alpha_mode: AlphaMode2d::Blend,
+                            ..default()
+                        })),
+                        Transform::from_xyz(0.0, 0.0, 0.1),
+                    )
+                ],
             ));
 
             fire_radius.set_visible(fire_radius_visible);
@@ -116,10 +153,13 @@ fn despawn_file_radius(
 
 fn update_fire_radius(
     game_tilemap: Single<&Tilemap, With<GameTilemap>>,
+    tiles: Query<&TilemapTile>,
     soldiers: Query<(&Soldier, &TilePosition, &Transform)>,
     mut fire_radii: Query<(&mut FireRadius, &Mesh2d, &mut Transform, &Children), Without<Soldier>>,
-    inner_fire_radii: Query<&Mesh2d, Without<FireRadius>>,
+    inner_fire_radii: Query<&Mesh2d, (Without<FireRadius>, Without<FireRadiusShadow>)>,
+    fire_radius_shadows: Query<&Mesh2d, With<FireRadiusShadow>>,
     selected_tile: Res<SelectedTile>,
+    level: Res<Level>,
     mut meshes: ResMut<Assets<Mesh>>,
 ) {
     for (mut fire_radius, fire_radius_mesh_2d, mut fire_radius_transform, fire_radius_children) in
@@ -147,6 +187,25 @@ fn update_fire_radius(
                             *inner_fire_radius_mesh = Circle::new(inner_radius).mesh().build();
                         }
                     }
+                    if let Ok(fire_radius_shadow_mesh_2d) =
+                        fire_radius_shadows.get(*inner_fire_radius_entity)
+                    {
+                        if let Some(fire_radius_shadow_mesh) =
+                            meshes.get_mut(&fire_radius_shadow_mesh_2d.0)
+                        {
+                            TileGrid::update(
+                                fire_radius_shadow_mesh,
+                                &get_occluded_tiles(
+                                    &game_tilemap,
+                                    &tiles,
+                                    &level,
+                                    soldier_tile_position.as_vec2(),
+                                    soldier.get_fire_radius(),
+                                ),
+                                game_tilemap.get_tile_size() as f32,
+                            );
+                        }
+                    }
                 }
 
                 fire_radius_transform.translation = soldier_transform.translation.with_z(1.0);
@@ -159,6 +218,28 @@ fn update_fire_radius(
     }
 }
 
+fn get_occluded_tiles(
+    game_tilemap: &Tilemap,
+    tiles: &Query<&TilemapTile>,
+    level: &Level,
+    origin: Vec2,
+    radius: f32,
+) -> Vec<Vec2> {
+    if level.has_line_of_sight() == false {
+        return Vec::new();
+    }
+
+    let range = radius.ceil() as i32;
+    (-range..=range)
+        .flat_map(|dx| (-range..=range).map(move |dy| Vec2::new(dx as f32, dy as f32)))
+        .filter(|offset| {
+            offset.length() <= radius
+                && game_tilemap.is_line_of_sight_blocked(tiles, origin, origin + *offset)
+        })
+        .map(|offset| offset * Vec2::new(1.0, -1.0) * game_tilemap.get_tile_size() as f32)
+        .collect()
+}
+
 fn update_fire_radius_alpha(
     fire_radii: Query<(&FireRadius, &MeshMaterial2d<ColorMaterial>, &Children)>,
     inner_fire_radii: Query<&MeshMaterial2d<ColorMaterial>, Without<FireRadius>>,
@@ -0,0 +1,275 @@
+use std::time::Duration;
+
+use bevy::{prelude::*, sprite_render::AlphaMode2d};
+
+use crate::game::{
+    GameState, GameTilemap,
+    assets::levels::Level,
+    entities::{
+        enemy::{Enemy, EnemyDomain, health::EnemyHealth, status_effect::EnemyStatusEffects},
+        soldier::{Soldier, SoldierVariant, config::SoldierAttack},
+        tile::{movement::TileMovement, position::TilePosition},
+        tilemap::{Tilemap, tile::TilemapTile},
+    },
+    meshes::annular_segment::AnnularSegment,
+    speed::GameSpeed,
+};
+
+const FLAME_TICKS: u32 = 3;
+const FLAME_TICK_INTERVAL_SECS: f32 = 0.08;
+
+#[derive(Component, Clone)]
+#[require(TilePosition)]
+pub struct Flame {
+    soldier_variant: SoldierVariant,
+    direction: Vec2,
+    damage: u32,
+    radius: f32,
+    alpha: f32,
+    elapsed: Duration,
+    applied_ticks: u32,
+    burned_enemies: Vec<Entity>,
+    source: Option<Entity>,
+}
+
+impl Flame {
+    pub fn new(soldier_variant: SoldierVariant, direction: Vec2, damage: u32, radius: f32) -> Self {
+        Self {
+            soldier_variant,
+            direction: direction.normalize_or(Vec2::X),
+            damage,
+            radius,
+            alpha: 0.75,
+            elapsed: Duration::ZERO,
+            applied_ticks: 0,
+            burned_enemies: Vec::new(),
+            source: None,
+        }
+    }
+    pub fn with_source(mut self, source: Entity) -> Self {
+        self.source = Some(source);
+        self
+    }
+    pub fn get_soldier_variant(&self) -> SoldierVariant {
+        self.soldier_variant
+    }
+    pub fn get_direction(&self) -> Vec2 {
+        self.direction
+    }
+    pub fn get_angle(&self) -> f32 {
+        self.soldier_variant
+            .get_config()
+            .get_attack()
+            .get_cone_angle()
+            .unwrap_or_default()
+    }
+    pub fn get_damage(&self) -> u32 {
+        self.damage
+    }
+    pub fn get_tick_damage(&self) -> u32 {
+        (self.get_damage() as f32 / FLAME_TICKS as f32).round() as u32
+    }
+    pub fn get_source(&self) -> Option<Entity> {
+        self.source
+    }
+    pub fn get_radius(&self) -> f32 {
+        self.radius
+    }
+    pub fn get_alpha(&self) -> f32 {
+        self.alpha
+    }
+    pub fn set_alpha(&mut self, alpha: f32) {
+        self.alpha = alpha;
+    }
+    pub fn add_elapsed(&mut self, delta_time: Duration) {
+        self.elapsed += delta_time;
+    }
+    pub fn is_tick_ready(&self) -> bool {
+        self.applied_ticks < FLAME_TICKS
+            && self.elapsed.as_secs_f32() >= FLAME_TICK_INTERVAL_SECS * self.applied_ticks as f32
+    }
+    pub fn add_applied_tick(&mut self) {
+        self.applied_ticks += 1;
+    }
+    pub fn is_finished(&self) -> bool {
+        self.applied_ticks >= FLAME_TICKS
+    }
+    pub fn is_burned(&self, enemy_entity: Entity) -> bool {
+        self.burned_enemies.contains(&enemy_entity)
+    }
+    pub fn add_burned_enemy(&mut self, enemy_entity: Entity) {
+        self.burned_enemies.push(enemy_entity);
+    }
+    pub fn contains(&self, origin: Vec2, position: Vec2) -> bool {
+        let offset = position - origin;
+
+        if offset.length() > self.get_radius() {
+            return false;
+        }
+        if offset.length() < 1e-3 {
+            return true;
+        }
+
+        self.get_direction().angle_to(offset).abs() <= self.get_angle() / 2.0
+    }
+}
+
+pub struct FlamePlugin;
+
+impl Plugin for FlamePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PreUpdate, init_flame);
+
+        app.add_systems(Update, update_flame.run_if(in_state(GameState::InGame)));
+    }
+}
+
+fn init_flame(
+    mut commands: Commands,
+    game_tilemap: Single<&Tilemap, With<GameTilemap>>,
+    flames: Query<(Entity, &Flame), Added<Flame>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    for (flame_entity, flame) in flames.iter() {
+        let mut mesh = AnnularSegment::new(0.0, 1.0);
+        let angle = flame.get_angle();
+        AnnularSegment::update(
+            &mut mesh,
+            0.0,
+            flame.get_radius() * game_tilemap.get_tile_size() as f32,
+            (-flame.get_direction().y).atan2(flame.get_direction().x) - angle / 2.0,
+            angle,
+        );
+
+        commands.entity(flame_entity).insert((
+            Mesh2d(meshes.add(mesh)),
+            MeshMaterial2d(materials.add(ColorMaterial {
+                color: Color::srgb(1.0, 0.5, 0.0).with_alpha(flame.get_alpha()),
+                alpha_mode: AlphaMode2d::Blend,
+                ..default()
+            })),
+        ));
+    }
+}
+
+fn update_flame(
+    mut commands: Commands,
+    mut flames: Query<(
+        Entity,
+        &mut Flame,
+        &TilePosition,
+        Option<&MeshMaterial2d<ColorMaterial>>,
+    )>,
+    game_tilemap: Single<&Tilemap, With<GameTilemap>>,
+    tiles: Query<&TilemapTile>,
+    mut enemies: Query<
+        (
+            Entity,
+            &Enemy,
+            &mut EnemyHealth,
+            &mut EnemyStatusEffects,
+            &TileMovement,
+            &TilePosition,
+        ),
+        Without<Flame>,
+    >,
+    mut soldiers: Query<&mut Soldier>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    level: Res<Level>,
+    game_speed: Res<GameSpeed>,
+    time: Res<Time>,
+) {
+    for (flame_entity, mut flame, flame_tile_position, flame_mesh_material_2d) in flames.iter_mut()
+    {
+        flame.add_elapsed(Duration::from_secs_f32(
+            time.delta_secs() * game_speed.as_f32(),
+        ));
+
+        while flame.is_tick_ready() == true {
+            let soldier_variant = flame.get_soldier_variant();
+            let soldier_config = soldier_variant.get_config();
+
+            let SoldierAttack::Cone {
+                damage_type,
+                status_effect,
+                ..
+            } = soldier_config.get_attack()
+            else {
+                flame.add_applied_tick();
+                continue;
+            };
+
+            for (
+                enemy_entity,
+                enemy,
+                mut enemy_health,
+                mut enemy_status_effects,
+                enemy_movement,
+                enemy_tile_position,
+            ) in enemies.iter_mut()
+            {
+                if enemy_movement.get_progress() <= 0.0
+                    || enemy_health.get_current() == 0
+                    || soldier_config.can_target(enemy.get_domain()) == false
+                    || flame.contains(flame_tile_position.as_vec2(), enemy_tile_position.as_vec2())
+                        == false
+                    || (level.has_line_of_sight() == true
+                        && enemy.get_domain() != EnemyDomain::Air
+                        && game_tilemap.is_line_of_sight_blocked(
+                            &tiles,
+                            flame_tile_position.as_vec2(),
+                            enemy_tile_position.as_vec2(),
+                        ))
+                {
+                    continue;
+                }
+
+                let previous_health = enemy_health.get_current();
+
+                enemy_health.damage(
+                    enemy
+                        .get_config()
+                        .get_armor()
+                        .resolve_damage(flame.get_tick_damage(), damage_type),
+                );
+                if let Some(status_effect) = status_effect {
+                    if flame.is_burned(enemy_entity) == false {
+                        enemy_status_effects.apply(match flame.get_source() {
+                            Some(source) => status_effect.with_source(source),
+                            None => status_effect,
+                        });
+                        flame.add_burned_enemy(enemy_entity);
+                    }
+                }
+
+                if let Some(mut soldier) = flame
+                    .get_source()
+                    .and_then(|source| soldiers.get_mut(source).ok())
+                {
+                    soldier.record_damage(previous_health, enemy_health.get_current());
+                }
+            }
+
+            flame.add_applied_tick();
+        }
+
+        if flame.get_alpha() < 1e-3 && flame.is_finished() == true {
+            commands.entity(flame_entity).despawn();
+            continue;
+        }
+
+        let current_alpha = flame.get_alpha();
+
+        if let Some(flame_mesh_material_2d) = flame_mesh_material_2d {
+            if let Some(flame_color_material) = materials.get_mut(&flame_mesh_material_2d.0) {
+                flame_color_material.color.set_alpha(current_alpha);
+            }
+        }
+
+        flame.set_alpha(current_alpha.lerp(
+            0.0,
+            time.delta_secs() * game_speed.as_f32() / Duration::from_millis(100).as_secs_f32(),
+        ));
+    }
+}
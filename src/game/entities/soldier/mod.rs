@@ -1,8 +1,14 @@
+pub mod aura;
+pub mod chain_beam;
 pub mod config;
 pub mod cooldown_indicator;
+pub mod fire_cone;
 pub mod fire_radius;
+pub mod flame;
 pub mod projectile;
 pub mod projectile_blast;
+pub mod rank_insignia;
+pub mod trap;
 
 use std::{ops::Deref, time::Duration};
 
@@ -12,80 +18,187 @@ use serde::{Deserialize, Serialize};
 
 use crate::game::{
     GameState, GameTilemap,
-    assets::audio::game::GameAudioAssets,
+    assets::{audio::game::GameAudioAssets, levels::Level},
     audio::{GameAudio, GameAudioVolume},
     entities::{
-        enemy::{Enemy, health::EnemyHealth},
+        enemy::{Enemy, EnemyDomain, health::EnemyHealth},
         soldier::{
-            config::{ROCKET_LAUNCHER_LEVELS, SNIPER_LEVELS, SOLDIER_LEVELS, SoldierConfig},
+            aura::SoldierAuraPlugin,
+            chain_beam::ChainBeamPlugin,
+            config::{
+                FLAMETHROWER_LEVELS, GUNBOAT_LEVELS, MINE_LEVELS, ROCKET_LAUNCHER_LEVELS,
+                SNIPER_LEVELS, SOLDIER_LEVELS, SOLDIER_RANKS, SPIKES_LEVELS, SUPPORT_LEVELS,
+                SoldierAttack, SoldierBonus, SoldierConfig, SoldierRankConfig, TESLA_LEVELS,
+            },
             cooldown_indicator::{CooldownIndicator, CooldownIndicatorPlugin},
+            fire_cone::{FireCone, FireConePlugin},
             fire_radius::{FireRadius, FireRadiusPlugin},
-            projectile::{Projectile, ProjectilePlugin, ProjectileVariant},
+            flame::{Flame, FlamePlugin},
+            projectile::{DamageType, Projectile, ProjectilePlugin, ProjectileVariant},
             projectile_blast::ProjectileBlastPlugin,
+            rank_insignia::{RankInsignia, RankInsigniaPlugin},
+            trap::{SoldierTrap, SoldierTrapPlugin},
         },
         tile::{
             movement::TileMovement,
             position::TilePosition,
             sprite::{TileSprite, TileSpriteVariant},
         },
+        tilemap::{
+            Tilemap,
+            tile::{TilemapTile, TilemapTileVariant},
+        },
     },
     speed::GameSpeed,
 };
 
+const PROJECTILE_PREDICTION_ITERATIONS: usize = 2;
+const PROJECTILE_OVERSHOOT_DISTANCE: f32 = 1.0;
+
 #[derive(Clone, Copy, PartialEq)]
 pub enum SoldierVariant {
     Soldier { level: usize },
     RocketLauncher { level: usize },
     Sniper { level: usize },
+    Flamethrower { level: usize },
+    Support { level: usize },
+    Mine { level: usize },
+    Spikes { level: usize },
+    Gunboat { level: usize },
+    Tesla { level: usize },
 }
 
 impl SoldierVariant {
+    pub fn get_all() -> [SoldierVariant; 9] {
+        [
+            SoldierVariant::Soldier { level: 0 },
+            SoldierVariant::RocketLauncher { level: 0 },
+            SoldierVariant::Sniper { level: 0 },
+            SoldierVariant::Flamethrower { level: 0 },
+            SoldierVariant::Support { level: 0 },
+            SoldierVariant::Tesla { level: 0 },
+            SoldierVariant::Mine { level: 0 },
+            SoldierVariant::Spikes { level: 0 },
+            SoldierVariant::Gunboat { level: 0 },
+        ]
+    }
     pub fn to_str(&self) -> &'static str {
         match self {
             SoldierVariant::Soldier { .. } => "soldier.variant.soldier",
             SoldierVariant::RocketLauncher { .. } => "soldier.variant.rocket_launcher",
             SoldierVariant::Sniper { .. } => "soldier.variant.sniper",
+            SoldierVariant::Flamethrower { .. } => "soldier.variant.flamethrower",
+            SoldierVariant::Support { .. } => "soldier.variant.support",
+            SoldierVariant::Mine { .. } => "soldier.variant.mine",
+            SoldierVariant::Spikes { .. } => "soldier.variant.spikes",
+            SoldierVariant::Gunboat { .. } => "soldier.variant.gunboat",
+            SoldierVariant::Tesla { .. } => "soldier.variant.tesla",
+        }
+    }
+    pub fn get_placement_tiles(&self) -> &'static [TilemapTileVariant] {
+        match self {
+            SoldierVariant::Mine { .. } | SoldierVariant::Spikes { .. } => {
+                &[TilemapTileVariant::Road, TilemapTileVariant::Bridge]
+            }
+            SoldierVariant::Gunboat { .. } => &[TilemapTileVariant::Water],
+            _ => &[TilemapTileVariant::Ground],
         }
     }
+    pub fn can_be_placed_on(&self, tile_variant: TilemapTileVariant) -> bool {
+        self.get_placement_tiles().contains(&tile_variant)
+    }
+    pub fn is_trap(&self) -> bool {
+        matches!(
+            self,
+            SoldierVariant::Mine { .. } | SoldierVariant::Spikes { .. }
+        )
+    }
+    pub fn is_placement_tile(tile_variant: TilemapTileVariant) -> bool {
+        SoldierVariant::get_all()
+            .iter()
+            .any(|soldier_variant| soldier_variant.can_be_placed_on(tile_variant))
+    }
     pub fn get_levels(&self) -> &'static [SoldierConfig] {
         match self {
             Self::Soldier { .. } => &SOLDIER_LEVELS,
             Self::RocketLauncher { .. } => &ROCKET_LAUNCHER_LEVELS,
             Self::Sniper { .. } => &SNIPER_LEVELS,
+            Self::Flamethrower { .. } => &FLAMETHROWER_LEVELS,
+            Self::Support { .. } => &SUPPORT_LEVELS,
+            Self::Mine { .. } => &MINE_LEVELS,
+            Self::Spikes { .. } => &SPIKES_LEVELS,
+            Self::Gunboat { .. } => &GUNBOAT_LEVELS,
+            Self::Tesla { .. } => &TESLA_LEVELS,
         }
     }
     pub fn get_level(&self) -> usize {
         match self {
             SoldierVariant::Soldier { level }
             | SoldierVariant::RocketLauncher { level }
-            | SoldierVariant::Sniper { level } => *level,
+            | SoldierVariant::Sniper { level }
+            | SoldierVariant::Flamethrower { level }
+            | SoldierVariant::Support { level }
+            | SoldierVariant::Mine { level }
+            | SoldierVariant::Spikes { level }
+            | SoldierVariant::Gunboat { level }
+            | SoldierVariant::Tesla { level } => *level,
         }
     }
     fn set_level(&mut self, new_level: usize) {
         match self {
             SoldierVariant::Soldier { level }
             | SoldierVariant::RocketLauncher { level }
-            | SoldierVariant::Sniper { level } => *level = new_level,
+            | SoldierVariant::Sniper { level }
+            | SoldierVariant::Flamethrower { level }
+            | SoldierVariant::Support { level }
+            | SoldierVariant::Mine { level }
+            | SoldierVariant::Spikes { level }
+            | SoldierVariant::Gunboat { level }
+            | SoldierVariant::Tesla { level } => *level = new_level,
+        }
+    }
+    pub fn with_level(mut self, level: usize) -> Self {
+        self.set_level(level);
+        self
+    }
+    pub fn get_parent_level(&self) -> Option<usize> {
+        self.get_levels()
+            .iter()
+            .position(|config| config.get_upgrades().contains(&self.get_level()))
+    }
+    pub fn get_tier(&self) -> usize {
+        let mut variant = *self;
+        let mut tier = 0;
+        while let Some(parent_level) = variant.get_parent_level() {
+            variant.set_level(parent_level);
+            tier += 1;
         }
+        tier
     }
-    pub fn get_max_level(&self) -> usize {
-        self.get_levels().len().saturating_sub(1)
+    pub fn get_max_tier(&self) -> usize {
+        (0..self.get_levels().len())
+            .map(|level| self.with_level(level).get_tier())
+            .max()
+            .unwrap_or_default()
     }
-    pub fn is_next_level_allowed(&self) -> bool {
-        self.get_level() < self.get_max_level()
+    pub fn get_upgrades(&self) -> Vec<SoldierVariant> {
+        self.get_config()
+            .get_upgrades()
+            .iter()
+            .map(|level| self.with_level(*level))
+            .collect()
     }
-    pub fn get_next_level(&self) -> usize {
-        self.get_level().saturating_add(1).min(self.get_max_level())
+    pub fn is_upgrade_allowed(&self, level: usize) -> bool {
+        self.get_config().get_upgrades().contains(&level)
     }
-    pub fn set_next_level(&mut self) {
-        self.set_level(self.get_next_level());
+    pub fn set_upgrade(&mut self, level: usize) {
+        if self.is_upgrade_allowed(level) == true {
+            self.set_level(level);
+        }
     }
     pub fn get_config(&self) -> &SoldierConfig {
         self.get_levels().get(self.get_level()).unwrap()
     }
-    pub fn get_next_level_config(&self) -> &SoldierConfig {
-        self.get_levels().get(self.get_next_level()).unwrap()
-    }
 }
 
 #[derive(Clone, Copy, Default)]
@@ -96,9 +209,28 @@ pub enum SoldierTargetPriority {
     Strongest,
     Weakest,
     Nearest,
+    Fastest,
+    HighestMaxHealth,
+    GroundFirst,
+    AirFirst,
+    NavalFirst,
 }
 
 impl SoldierTargetPriority {
+    pub fn get_all() -> [SoldierTargetPriority; 10] {
+        [
+            SoldierTargetPriority::First,
+            SoldierTargetPriority::Last,
+            SoldierTargetPriority::Strongest,
+            SoldierTargetPriority::Weakest,
+            SoldierTargetPriority::Nearest,
+            SoldierTargetPriority::Fastest,
+            SoldierTargetPriority::HighestMaxHealth,
+            SoldierTargetPriority::GroundFirst,
+            SoldierTargetPriority::AirFirst,
+            SoldierTargetPriority::NavalFirst,
+        ]
+    }
     pub fn to_str(&self) -> &'static str {
         match self {
             SoldierTargetPriority::First => "soldier.target_priority.first",
@@ -106,6 +238,11 @@ impl SoldierTargetPriority {
             SoldierTargetPriority::Strongest => "soldier.target_priority.strongest",
             SoldierTargetPriority::Weakest => "soldier.target_priority.weakest",
             SoldierTargetPriority::Nearest => "soldier.target_priority.nearest",
+            SoldierTargetPriority::Fastest => "soldier.target_priority.fastest",
+            SoldierTargetPriority::HighestMaxHealth => "soldier.target_priority.highest_max_health",
+            SoldierTargetPriority::GroundFirst => "soldier.target_priority.ground_first",
+            SoldierTargetPriority::AirFirst => "soldier.target_priority.air_first",
+            SoldierTargetPriority::NavalFirst => "soldier.target_priority.naval_first",
         }
     }
     pub fn as_index(&self) -> usize {
@@ -115,6 +252,11 @@ impl SoldierTargetPriority {
             SoldierTargetPriority::Strongest => 2,
             SoldierTargetPriority::Weakest => 3,
             SoldierTargetPriority::Nearest => 4,
+            SoldierTargetPriority::Fastest => 5,
+            SoldierTargetPriority::HighestMaxHealth => 6,
+            SoldierTargetPriority::GroundFirst => 7,
+            SoldierTargetPriority::AirFirst => 8,
+            SoldierTargetPriority::NavalFirst => 9,
         }
     }
     pub fn from_index(index: usize) -> Self {
@@ -124,9 +266,51 @@ impl SoldierTargetPriority {
             2 => SoldierTargetPriority::Strongest,
             3 => SoldierTargetPriority::Weakest,
             4 => SoldierTargetPriority::Nearest,
+            5 => SoldierTargetPriority::Fastest,
+            6 => SoldierTargetPriority::HighestMaxHealth,
+            7 => SoldierTargetPriority::GroundFirst,
+            8 => SoldierTargetPriority::AirFirst,
+            9 => SoldierTargetPriority::NavalFirst,
             _ => SoldierTargetPriority::default(),
         }
     }
+    pub fn get_domain(&self) -> Option<EnemyDomain> {
+        match self {
+            SoldierTargetPriority::GroundFirst => Some(EnemyDomain::Ground),
+            SoldierTargetPriority::AirFirst => Some(EnemyDomain::Air),
+            SoldierTargetPriority::NavalFirst => Some(EnemyDomain::Naval),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq)]
+pub enum SoldierTargetMode {
+    #[default]
+    Switch,
+    Sticky,
+}
+
+impl SoldierTargetMode {
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            SoldierTargetMode::Switch => "soldier.target_mode.switch",
+            SoldierTargetMode::Sticky => "soldier.target_mode.sticky",
+        }
+    }
+    pub fn as_index(&self) -> usize {
+        match self {
+            SoldierTargetMode::Switch => 0,
+            SoldierTargetMode::Sticky => 1,
+        }
+    }
+    pub fn from_index(index: usize) -> Self {
+        match index {
+            0 => SoldierTargetMode::Switch,
+            1 => SoldierTargetMode::Sticky,
+            _ => SoldierTargetMode::default(),
+        }
+    }
 }
 
 #[derive(Default, Clone, Copy, Serialize, Deserialize)]
@@ -163,7 +347,17 @@ impl SoldierPlacement {
 pub struct Soldier {
     variant: SoldierVariant,
     cooldown: Duration,
+    aim_direction: Vec2,
+    aura_bonus: SoldierBonus,
+    used_charges: u32,
+    damage_dealt: u32,
+    kills: u32,
     target_priority: SoldierTargetPriority,
+    target_mode: SoldierTargetMode,
+    target: Option<Entity>,
+    focus_target: Option<Entity>,
+    focus_time: Duration,
+    lifetime: Option<Duration>,
     update_required: bool,
 }
 
@@ -172,10 +366,24 @@ impl Soldier {
         Self {
             variant,
             cooldown: Duration::ZERO,
+            aim_direction: Vec2::X,
+            aura_bonus: SoldierBonus::default(),
+            used_charges: 0,
+            damage_dealt: 0,
+            kills: 0,
             target_priority: SoldierTargetPriority::default(),
+            target_mode: SoldierTargetMode::default(),
+            target: None,
+            focus_target: None,
+            focus_time: Duration::ZERO,
+            lifetime: None,
             update_required: false,
         }
     }
+    pub fn with_lifetime(mut self, lifetime: Duration) -> Self {
+        self.lifetime = Some(lifetime);
+        self
+    }
     pub fn get_variant(&self) -> SoldierVariant {
         self.variant
     }
@@ -189,13 +397,77 @@ impl Soldier {
         self.variant = variant;
     }
     pub fn get_damage(&self) -> u32 {
-        self.get_config().get_damage()
+        self.get_bonus()
+            .apply_damage(self.get_config().get_damage())
+    }
+    pub fn get_damage_type(&self) -> Option<DamageType> {
+        self.get_config().get_damage_type()
     }
     pub fn get_fire_radius(&self) -> f32 {
-        self.get_config().get_fire_radius()
+        self.get_bonus()
+            .apply_fire_radius(self.get_config().get_fire_radius())
     }
     pub fn get_fire_rate(&self) -> Duration {
-        self.get_config().get_fire_rate()
+        self.get_bonus()
+            .apply_fire_rate(self.get_config().get_fire_rate())
+    }
+    pub fn get_bonus(&self) -> SoldierBonus {
+        self.aura_bonus.sum(&self.get_rank_bonus())
+    }
+    pub fn get_aura_bonus(&self) -> SoldierBonus {
+        self.aura_bonus
+    }
+    pub fn set_aura_bonus(&mut self, aura_bonus: SoldierBonus) {
+        self.aura_bonus = aura_bonus;
+    }
+    pub fn get_damage_dealt(&self) -> u32 {
+        self.damage_dealt
+    }
+    pub fn add_damage_dealt(&mut self, damage: u32) {
+        self.damage_dealt = self.damage_dealt.saturating_add(damage);
+    }
+    pub fn get_kills(&self) -> u32 {
+        self.kills
+    }
+    pub fn add_kill(&mut self) {
+        self.kills = self.kills.saturating_add(1);
+    }
+    pub fn record_damage(&mut self, previous_health: u32, current_health: u32) {
+        self.add_damage_dealt(previous_health.saturating_sub(current_health));
+        if previous_health > 0 && current_health == 0 {
+            self.add_kill();
+        }
+    }
+    pub fn get_rank(&self) -> usize {
+        SOLDIER_RANKS
+            .iter()
+            .take_while(|rank| rank.is_reached(self.kills, self.damage_dealt))
+            .count()
+    }
+    pub fn get_max_rank(&self) -> usize {
+        SOLDIER_RANKS.len()
+    }
+    pub fn get_next_rank_config(&self) -> Option<&'static SoldierRankConfig> {
+        SOLDIER_RANKS.get(self.get_rank())
+    }
+    pub fn get_rank_bonus(&self) -> SoldierBonus {
+        self.get_rank()
+            .checked_sub(1)
+            .and_then(|rank| SOLDIER_RANKS.get(rank))
+            .map(|rank| rank.get_bonus())
+            .unwrap_or_default()
+    }
+    pub fn get_used_charges(&self) -> u32 {
+        self.used_charges
+    }
+    pub fn get_remaining_charges(&self) -> Option<u32> {
+        self.get_config()
+            .get_attack()
+            .get_trap_charges()
+            .map(|charges| charges.saturating_sub(self.used_charges))
+    }
+    pub fn use_charge(&mut self) {
+        self.used_charges = self.used_charges.saturating_add(1);
     }
     pub fn get_cooldown(&self) -> Duration {
         self.cooldown
@@ -209,12 +481,64 @@ impl Soldier {
     pub fn update_cooldown(&mut self) {
         self.cooldown = self.get_fire_rate();
     }
+    pub fn get_aim_direction(&self) -> Vec2 {
+        self.aim_direction
+    }
+    pub fn set_aim_direction(&mut self, direction: Vec2) {
+        self.aim_direction = direction.try_normalize().unwrap_or(self.aim_direction);
+    }
     pub fn get_target_priority(&self) -> SoldierTargetPriority {
         self.target_priority
     }
     pub fn set_target_priority(&mut self, priority: SoldierTargetPriority) {
         self.target_priority = priority;
     }
+    pub fn get_target_mode(&self) -> SoldierTargetMode {
+        self.target_mode
+    }
+    pub fn set_target_mode(&mut self, target_mode: SoldierTargetMode) {
+        self.target_mode = target_mode;
+    }
+    pub fn get_target(&self) -> Option<Entity> {
+        self.target
+    }
+    pub fn set_target(&mut self, target: Option<Entity>) {
+        self.target = target;
+    }
+    pub fn get_focus_target(&self) -> Option<Entity> {
+        self.focus_target
+    }
+    pub fn set_focus_target(&mut self, focus_target: Entity, duration: Duration) {
+        self.focus_target = Some(focus_target);
+        self.focus_time = duration;
+    }
+    pub fn decrease_focus_time(&mut self, delta_time: Duration) {
+        self.focus_time = self.focus_time.saturating_sub(delta_time);
+        if self.focus_time == Duration::ZERO {
+            self.focus_target = None;
+        }
+    }
+    pub fn get_lifetime(&self) -> Option<Duration> {
+        self.lifetime
+    }
+    pub fn is_temporary(&self) -> bool {
+        self.lifetime.is_some()
+    }
+    pub fn blocks_route(&self) -> bool {
+        self.is_temporary() == false && self.variant.is_trap() == false
+    }
+    pub fn decrease_lifetime(&mut self, delta_time: Duration) {
+        if let Some(lifetime) = self.lifetime.as_mut() {
+            *lifetime = lifetime.saturating_sub(delta_time);
+        }
+    }
+    pub fn get_sell_price(&self) -> u32 {
+        if self.is_temporary() == true {
+            0
+        } else {
+            self.get_config().get_sell_price()
+        }
+    }
     pub fn get_update_required(&self) -> bool {
         self.update_required
     }
@@ -236,10 +560,16 @@ pub struct SoldierPlugin;
 impl Plugin for SoldierPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins((
+            SoldierAuraPlugin,
+            ChainBeamPlugin,
             CooldownIndicatorPlugin,
+            FireConePlugin,
             FireRadiusPlugin,
+            FlamePlugin,
             ProjectilePlugin,
             ProjectileBlastPlugin,
+            RankInsigniaPlugin,
+            SoldierTrapPlugin,
         ));
 
         app.add_systems(PreUpdate, init_soldier);
@@ -261,7 +591,20 @@ fn init_soldier(
         commands
             .entity(soldier_entity)
             .insert(TileSprite::new(soldier.get_variant().into()));
-        soldier_tile_position.set_z(2.0);
+
+        if soldier
+            .get_config()
+            .get_attack()
+            .get_trap_charges()
+            .is_some()
+        {
+            commands
+                .entity(soldier_entity)
+                .insert(SoldierTrap::default());
+            soldier_tile_position.set_z(-0.5);
+        } else {
+            soldier_tile_position.set_z(2.0);
+        }
 
         commands
             .entity(game_tilemap.entity())
@@ -270,23 +613,47 @@ fn init_soldier(
         commands
             .entity(game_tilemap.entity())
             .with_child(CooldownIndicator::new(soldier_entity));
+
+        commands
+            .entity(game_tilemap.entity())
+            .with_child(RankInsignia::new(soldier_entity));
+
+        if soldier.get_config().get_attack().get_cone_angle().is_some() {
+            commands
+                .entity(game_tilemap.entity())
+                .with_child(FireCone::new(soldier_entity));
+        }
     }
 }
 
 fn update_soldier(
     mut commands: Commands,
-    mut soldiers: Query<(&mut Soldier, &TilePosition, &mut TileSprite, &mut Transform)>,
-    game_tilemap: Single<Entity, With<GameTilemap>>,
-    enemies: Query<(Entity, &EnemyHealth, &TileMovement, &TilePosition), With<Enemy>>,
+    mut soldiers: Query<(
+        Entity,
+        &mut Soldier,
+        &TilePosition,
+        &mut TileSprite,
+        &mut Transform,
+    )>,
+    game_tilemap: Single<(Entity, &Tilemap), With<GameTilemap>>,
+    tiles: Query<&TilemapTile>,
+    enemies: Query<(Entity, &Enemy, &EnemyHealth, &TileMovement, &TilePosition)>,
     projectiles: Query<&Projectile>,
+    level: Res<Level>,
     game_audio: Single<Entity, With<GameAudio>>,
     game_audio_volume: Res<Persistent<GameAudioVolume>>,
     game_audio_assets: Res<GameAudioAssets>,
 ) {
+    let (game_tilemap_entity, game_tilemap) = game_tilemap.into_inner();
     let mut projectiles = projectiles.iter().cloned().collect::<Vec<Projectile>>();
 
-    for (mut soldier, soldier_tile_position, mut soldier_tile_sprite, mut soldier_transform) in
-        soldiers.iter_mut()
+    for (
+        soldier_entity,
+        mut soldier,
+        soldier_tile_position,
+        mut soldier_tile_sprite,
+        mut soldier_transform,
+    ) in soldiers.iter_mut()
     {
         if soldier.get_update_required() == true {
             let variant = soldier.get_variant();
@@ -294,25 +661,37 @@ fn update_soldier(
             soldier.set_update_required(false);
         }
 
-        if soldier.get_cooldown() > Duration::ZERO {
+        if soldier.get_cooldown() > Duration::ZERO
+            || soldier.get_config().get_attack().is_passive() == true
+        {
             continue;
         }
 
         let mut sorted_enemies = enemies
             .iter()
-            .filter(|(_, _, enemy_movement, _)| enemy_movement.get_progress() > 0.0)
+            .filter(|(_, enemy, _, enemy_movement, _)| {
+                enemy_movement.get_progress() > 0.0
+                    && soldier.get_config().can_target(enemy.get_domain())
+                    && soldier.get_damage_type().is_some_and(|damage_type| {
+                        enemy
+                            .get_config()
+                            .get_armor()
+                            .resolve_damage(soldier.get_damage(), damage_type)
+                            > 0
+                    })
+            })
             .collect::<Vec<_>>();
 
         sorted_enemies.sort_unstable_by(
-            |(_, enemy_a_health, enemy_a_movement, enemy_a_tile_position),
-             (_, enemy_b_health, enemy_b_movement, enemy_b_tile_position)| {
+            |(_, enemy_a, enemy_a_health, enemy_a_movement, enemy_a_tile_position),
+             (_, enemy_b, enemy_b_health, enemy_b_movement, enemy_b_tile_position)| {
                 match soldier.get_target_priority() {
-                    SoldierTargetPriority::First => enemy_b_movement
-                        .get_progress()
-                        .total_cmp(&enemy_a_movement.get_progress()),
-                    SoldierTargetPriority::Last => enemy_a_movement
-                        .get_progress()
-                        .total_cmp(&enemy_b_movement.get_progress()),
+                    SoldierTargetPriority::First => enemy_a_movement
+                        .get_remaining_distance()
+                        .total_cmp(&enemy_b_movement.get_remaining_distance()),
+                    SoldierTargetPriority::Last => enemy_b_movement
+                        .get_remaining_distance()
+                        .total_cmp(&enemy_a_movement.get_remaining_distance()),
                     SoldierTargetPriority::Nearest => enemy_a_tile_position
                         .as_vec2()
                         .distance(soldier_tile_position.as_vec2())
@@ -322,31 +701,88 @@ fn update_soldier(
                                 .distance(soldier_tile_position.as_vec2()),
                         )
                         .then_with(|| {
-                            enemy_b_movement
-                                .get_progress()
-                                .total_cmp(&enemy_a_movement.get_progress())
+                            enemy_a_movement
+                                .get_remaining_distance()
+                                .total_cmp(&enemy_b_movement.get_remaining_distance())
                         }),
                     SoldierTargetPriority::Strongest => enemy_b_health
                         .get_current()
                         .cmp(&enemy_a_health.get_current())
                         .then_with(|| {
-                            enemy_b_movement
-                                .get_progress()
-                                .total_cmp(&enemy_a_movement.get_progress())
+                            enemy_a_movement
+                                .get_remaining_distance()
+                                .total_cmp(&enemy_b_movement.get_remaining_distance())
                         }),
                     SoldierTargetPriority::Weakest => enemy_a_health
                         .get_current()
                         .cmp(&enemy_b_health.get_current())
                         .then_with(|| {
-                            enemy_b_movement
-                                .get_progress()
-                                .total_cmp(&enemy_a_movement.get_progress())
+                            enemy_a_movement
+                                .get_remaining_distance()
+                                .total_cmp(&enemy_b_movement.get_remaining_distance())
                         }),
+                    SoldierTargetPriority::Fastest => (enemy_b_movement.get_speed()
+                        * enemy_b_movement.get_speed_multiplier())
+                    .total_cmp(
+                        &(enemy_a_movement.get_speed() * enemy_a_movement.get_speed_multiplier()),
+                    )
+                    .then_with(|| {
+                        enemy_a_movement
+                            .get_remaining_distance()
+                            .total_cmp(&enemy_b_movement.get_remaining_distance())
+                    }),
+                    SoldierTargetPriority::HighestMaxHealth => enemy_b_health
+                        .get_max()
+                        .cmp(&enemy_a_health.get_max())
+                        .then_with(|| {
+                            enemy_a_movement
+                                .get_remaining_distance()
+                                .total_cmp(&enemy_b_movement.get_remaining_distance())
+                        }),
+                    SoldierTargetPriority::GroundFirst
+                    | SoldierTargetPriority::AirFirst
+                    | SoldierTargetPriority::NavalFirst => {
+                        let priority_domain = soldier.get_target_priority().get_domain();
+                        (Some(enemy_b.get_domain()) == priority_domain)
+                            .cmp(&(Some(enemy_a.get_domain()) == priority_domain))
+                            .then_with(|| {
+                                enemy_a_movement
+                                    .get_remaining_distance()
+                                    .total_cmp(&enemy_b_movement.get_remaining_distance())
+                            })
+                    }
                 }
             },
         );
 
-        for (enemy_entity, enemy_health, enemy_movement, enemy_tile_position) in
+        let sticky_target = soldier
+            .get_target()
+            .filter(|_| soldier.get_target_mode() == SoldierTargetMode::Sticky);
+
+        for preferred_target in [sticky_target, soldier.get_focus_target()]
+            .into_iter()
+            .flatten()
+        {
+            if let Some(index) = sorted_enemies
+                .iter()
+                .position(|(enemy_entity, ..)| *enemy_entity == preferred_target)
+            {
+                let preferred_enemy = sorted_enemies.remove(index);
+                sorted_enemies.insert(0, preferred_enemy);
+            }
+        }
+
+        let is_occluded = |enemy: &Enemy, target_position: Vec2| {
+            level.has_line_of_sight() == true
+                && enemy.get_domain() != EnemyDomain::Air
+                && game_tilemap.is_line_of_sight_blocked(
+                    &tiles,
+                    soldier_tile_position.as_vec2(),
+                    target_position,
+                )
+        };
+
+        for (enemy_entity, enemy, enemy_health, enemy_movement, enemy_tile_position) in
             sorted_enemies.iter()
         {
             if soldier_tile_position
@@ -356,53 +792,124 @@ fn update_soldier(
             {
                 continue;
             }
-            if enemy_health.get_current()
-                <= projectiles
-                    .iter()
-                    .filter(|projectile| projectile.get_target() == *enemy_entity)
-                    .map(|projectile| projectile.get_damage())
-                    .sum()
-            {
-                continue;
-            }
+            let target_position = match soldier.get_config().get_attack() {
+                SoldierAttack::Projectile(projectile_variant) => {
+                    if enemy_health.get_current()
+                        <= projectiles
+                            .iter()
+                            .map(|projectile| {
+                                enemy.get_config().get_armor().resolve_damage(
+                                    projectile.get_damage_to(*enemy_entity),
+                                    projectile.get_damage_type(),
+                                )
+                            })
+                            .sum()
+                    {
+                        continue;
+                    }
+
+                    let projectile_speed = projectile_variant.get_kinematics().get_speed();
+
+                    let mut predicted_position = enemy_tile_position.as_vec2();
+                    for _ in 0..PROJECTILE_PREDICTION_ITERATIONS {
+                        let flight_time =
+                            soldier_tile_position.as_vec2().distance(predicted_position)
+                                / projectile_speed;
+                        predicted_position = enemy_movement.position_at_progress(
+                            enemy_movement.get_progress()
+                                + flight_time * enemy_movement.get_speed_multiplier()
+                                    / enemy_movement.get_duration().as_secs_f32(),
+                        );
+                    }
+
+                    if is_occluded(enemy, predicted_position) == true {
+                        continue;
+                    }
+
+                    let flight_distance = if projectile_variant.get_kinematics().is_homing() == true
+                        || projectile_variant.get_max_pierce().is_some()
+                    {
+                        soldier.get_fire_radius()
+                    } else {
+                        soldier_tile_position.as_vec2().distance(predicted_position)
+                    };
+
+                    let projectile =
+                        Projectile::new(projectile_variant, *enemy_entity, soldier.get_damage())
+                            .with_source(soldier_entity)
+                            .with_target_domains(soldier.get_config().get_target_domains())
+                            .with_direction(predicted_position - soldier_tile_position.as_vec2())
+                            .with_max_distance(flight_distance + PROJECTILE_OVERSHOOT_DISTANCE)
+                            .with_chain_targets(
+                                projectile_variant
+                                    .get_chain_targets(
+                                        enemy_tile_position.as_vec2(),
+                                        &sorted_enemies
+                                            .iter()
+                                            .filter(|(chain_entity, ..)| {
+                                                chain_entity != enemy_entity
+                                            })
+                                            .map(|(chain_entity, .., chain_tile_position)| {
+                                                (*chain_entity, chain_tile_position.as_vec2())
+                                            })
+                                            .collect::<Vec<_>>(),
+                                    )
+                                    .into_iter()
+                                    .map(|(chain_entity, _)| chain_entity)
+                                    .collect(),
+                            );
+                    commands.entity(game_tilemap_entity).with_child((
+                        projectile.clone(),
+                        TilePosition::from_vec2(soldier_tile_position.as_vec2()),
+                    ));
+                    projectiles.push(projectile);
+
+                    commands.entity(game_audio.entity()).with_child((
+                        AudioPlayer::new(match projectile_variant {
+                            ProjectileVariant::Bullet { .. }
+                            | ProjectileVariant::Piercing { .. }
+                            | ProjectileVariant::Chain { .. } => {
+                                game_audio_assets.get_random_bullet_shoot()
+                            }
+                            ProjectileVariant::Rocket { .. } => {
+                                game_audio_assets.get_random_rocket_shoot()
+                            }
+                        }),
+                        PlaybackSettings {
+                            mode: PlaybackMode::Remove,
+                            volume: game_audio_volume.get_sfx_volume(),
+                            ..default()
+                        },
+                    ));
+
+                    predicted_position
+                }
+                SoldierAttack::Cone { .. } => {
+                    if is_occluded(enemy, enemy_tile_position.as_vec2()) == true {
+                        continue;
+                    }
+
+                    commands.entity(game_tilemap_entity).with_child((
+                        Flame::new(
+                            soldier.get_variant(),
+                            enemy_tile_position.as_vec2() - soldier_tile_position.as_vec2(),
+                            soldier.get_damage(),
+                            soldier.get_fire_radius(),
+                        )
+                        .with_source(soldier_entity),
+                        TilePosition::from_vec2(soldier_tile_position.as_vec2()).with_z(1.5),
+                    ));
 
-            let projectile_variant = soldier.get_config().get_projectile_variant();
-            let projectile_duration = projectile_variant.get_config().get_duration();
-
-            let enemy_progress_on_hit = enemy_movement.get_progress()
-                + projectile_duration.as_secs_f32() / enemy_movement.get_duration().as_secs_f32();
-
-            let projectile =
-                Projectile::new(projectile_variant, *enemy_entity, soldier.get_damage());
-            commands.entity(game_tilemap.entity()).with_child((
-                projectile,
-                TileMovement::new(
-                    vec![
-                        soldier_tile_position.as_vec2(),
-                        enemy_movement.position_at_progress(enemy_progress_on_hit),
-                    ],
-                    projectile_duration,
-                    None,
-                ),
-            ));
-            projectiles.push(projectile);
-
-            commands.entity(game_audio.entity()).with_child((
-                AudioPlayer::new(match projectile_variant {
-                    ProjectileVariant::Bullet => game_audio_assets.get_random_bullet_shoot(),
-                    ProjectileVariant::Rocket { .. } => game_audio_assets.get_random_rocket_shoot(),
-                }),
-                PlaybackSettings {
-                    mode: PlaybackMode::Remove,
-                    volume: game_audio_volume.get_sfx_volume(),
-                    ..default()
-                },
-            ));
+                    enemy_tile_position.as_vec2()
+                }
+                SoldierAttack::Aura(_) | SoldierAttack::Trap { .. } => continue,
+            };
 
             soldier.update_cooldown();
+            soldier.set_target(Some(*enemy_entity));
+            soldier.set_aim_direction(target_position - soldier_tile_position.as_vec2());
 
-            let enemy_direction = soldier_tile_position.as_vec2()
-                - enemy_movement.position_at_progress(enemy_progress_on_hit);
+            let enemy_direction = soldier_tile_position.as_vec2() - target_position;
             let scale_x = if enemy_direction.x < 0.0 { 1.0 } else { -1.0 };
             soldier_transform.scale.x = scale_x;
 
@@ -412,19 +919,32 @@ fn update_soldier(
 }
 
 fn update_soldier_cooldown(
+    mut commands: Commands,
     mut soldiers: Query<(Entity, &mut Soldier)>,
     mut cooldown_indicators: Query<&mut CooldownIndicator>,
     game_speed: Res<GameSpeed>,
     time: Res<Time>,
 ) {
     for (soldier_entity, mut soldier) in soldiers.iter_mut() {
+        let delta_time = Duration::from_secs_f32(time.delta_secs() * game_speed.as_f32());
+
+        if soldier.get_focus_target().is_some() {
+            soldier.decrease_focus_time(delta_time);
+        }
+
+        if soldier.is_temporary() == true {
+            soldier.decrease_lifetime(delta_time);
+            if soldier.get_lifetime() == Some(Duration::ZERO) {
+                commands.entity(soldier_entity).despawn();
+                continue;
+            }
+        }
+
         if soldier.get_cooldown() == Duration::ZERO {
             continue;
         }
 
-        soldier.decrease_cooldown(Duration::from_secs_f32(
-            time.delta_secs() * game_speed.as_f32(),
-        ));
+        soldier.decrease_cooldown(delta_time);
 
         for mut cooldown_indicator in cooldown_indicators.iter_mut() {
             if cooldown_indicator.get_soldier_entity() == soldier_entity {
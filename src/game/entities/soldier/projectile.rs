@@ -1,4 +1,4 @@
-use std::{f32::consts::FRAC_PI_2, ops::Deref, time::Duration};
+use std::{f32::consts::FRAC_PI_2, ops::Deref};
 
 use bevy::prelude::*;
 
@@ -6,60 +6,214 @@ use crate::game::{
     GameState, GameTilemap,
     assets::images::entity::{EntityAssets, UtilSpriteVariant},
     entities::{
-        enemy::{Enemy, health::EnemyHealth},
-        soldier::projectile_blast::ProjectileBlast,
+        enemy::{
+            Enemy, EnemyDomain,
+            health::EnemyHealth,
+            status_effect::{EnemyStatusEffect, EnemyStatusEffects},
+        },
+        soldier::{Soldier, chain_beam::ChainBeam, projectile_blast::ProjectileBlast},
         tile::{movement::TileMovement, position::TilePosition, sprite::TileSprite},
     },
+    speed::GameSpeed,
 };
 
+const PROJECTILE_HIT_RADIUS: f32 = 0.4;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ProjectileKinematics {
+    Ballistic { speed: f32 },
+    Homing { speed: f32 },
+}
+
+impl ProjectileKinematics {
+    pub fn get_speed(&self) -> f32 {
+        match self {
+            ProjectileKinematics::Ballistic { speed } | ProjectileKinematics::Homing { speed } => {
+                *speed
+            }
+        }
+    }
+    pub fn is_homing(&self) -> bool {
+        matches!(self, ProjectileKinematics::Homing { .. })
+    }
+}
+
 pub struct ProjectileVariantConfig {
-    duration: Duration,
     sprite_scale: Vec3,
 }
 
 impl ProjectileVariantConfig {
-    pub fn get_duration(&self) -> Duration {
-        self.duration
-    }
     pub fn get_sprite_scale(&self) -> Vec3 {
         self.sprite_scale
     }
 }
 
+#[derive(Clone, Copy, PartialEq)]
+pub enum DamageType {
+    Kinetic,
+    Explosive,
+    Piercing,
+    Fire,
+}
+
 #[derive(Clone, Copy, PartialEq)]
 pub enum ProjectileVariant {
-    Bullet,
-    Rocket { blast_radius: f32 },
+    Bullet {
+        kinematics: ProjectileKinematics,
+        damage_type: DamageType,
+        status_effect: Option<EnemyStatusEffect>,
+    },
+    Rocket {
+        kinematics: ProjectileKinematics,
+        damage_type: DamageType,
+        blast_radius: f32,
+        status_effect: Option<EnemyStatusEffect>,
+    },
+    Piercing {
+        kinematics: ProjectileKinematics,
+        damage_type: DamageType,
+        hit_width: f32,
+        max_pierce: usize,
+        status_effect: Option<EnemyStatusEffect>,
+    },
+    Chain {
+        kinematics: ProjectileKinematics,
+        damage_type: DamageType,
+        jumps: usize,
+        jump_radius: f32,
+        damage_falloff: f32,
+        status_effect: Option<EnemyStatusEffect>,
+    },
 }
 
 impl ProjectileVariant {
+    pub fn get_kinematics(&self) -> ProjectileKinematics {
+        match self {
+            ProjectileVariant::Bullet { kinematics, .. }
+            | ProjectileVariant::Rocket { kinematics, .. }
+            | ProjectileVariant::Piercing { kinematics, .. }
+            | ProjectileVariant::Chain { kinematics, .. } => *kinematics,
+        }
+    }
+    pub fn get_damage_type(&self) -> DamageType {
+        match self {
+            ProjectileVariant::Bullet { damage_type, .. }
+            | ProjectileVariant::Rocket { damage_type, .. }
+            | ProjectileVariant::Piercing { damage_type, .. }
+            | ProjectileVariant::Chain { damage_type, .. } => *damage_type,
+        }
+    }
     pub fn get_blast_radius(&self) -> Option<f32> {
         match self {
-            ProjectileVariant::Rocket { blast_radius } => Some(*blast_radius),
+            ProjectileVariant::Rocket { blast_radius, .. } => Some(*blast_radius),
             _ => None,
         }
     }
+    pub fn get_status_effect(&self) -> Option<EnemyStatusEffect> {
+        match self {
+            ProjectileVariant::Bullet { status_effect, .. }
+            | ProjectileVariant::Rocket { status_effect, .. }
+            | ProjectileVariant::Piercing { status_effect, .. }
+            | ProjectileVariant::Chain { status_effect, .. } => *status_effect,
+        }
+    }
+    pub fn get_hit_width(&self) -> Option<f32> {
+        match self {
+            ProjectileVariant::Piercing { hit_width, .. } => Some(*hit_width),
+            _ => None,
+        }
+    }
+    pub fn get_max_pierce(&self) -> Option<usize> {
+        match self {
+            ProjectileVariant::Piercing { max_pierce, .. } => Some(*max_pierce),
+            _ => None,
+        }
+    }
+    pub fn get_chain_jumps(&self) -> usize {
+        match self {
+            ProjectileVariant::Chain { jumps, .. } => *jumps,
+            _ => 0,
+        }
+    }
+    pub fn get_chain_jump_radius(&self) -> f32 {
+        match self {
+            ProjectileVariant::Chain { jump_radius, .. } => *jump_radius,
+            _ => 0.0,
+        }
+    }
+    pub fn get_chain_damage(&self, damage: u32, jump: usize) -> u32 {
+        match self {
+            ProjectileVariant::Chain { damage_falloff, .. } => (damage as f32
+                * (1.0 - damage_falloff).clamp(0.0, 1.0).powi(jump as i32))
+            .round() as u32,
+            _ => damage,
+        }
+    }
+    pub fn get_chain_targets(
+        &self,
+        origin: Vec2,
+        enemies: &[(Entity, Vec2)],
+    ) -> Vec<(Entity, Vec2)> {
+        let mut chain_targets: Vec<(Entity, Vec2)> = Vec::new();
+        let mut position = origin;
+
+        for _ in 0..self.get_chain_jumps() {
+            let Some(next_target) = enemies
+                .iter()
+                .filter(|(enemy_entity, enemy_position)| {
+                    chain_targets
+                        .iter()
+                        .any(|(chain_entity, _)| chain_entity == enemy_entity)
+                        == false
+                        && enemy_position.distance(position) <= self.get_chain_jump_radius()
+                })
+                .min_by(|(_, enemy_a_position), (_, enemy_b_position)| {
+                    enemy_a_position
+                        .distance(position)
+                        .total_cmp(&enemy_b_position.distance(position))
+                })
+            else {
+                break;
+            };
+
+            position = next_target.1;
+            chain_targets.push(*next_target);
+        }
+
+        chain_targets
+    }
 }
 
-#[derive(Component, Clone, Copy)]
+#[derive(Component, Clone)]
 #[require(TilePosition)]
 pub struct Projectile {
     variant: ProjectileVariant,
+    source: Option<Entity>,
     target: Entity,
+    target_domains: &'static [EnemyDomain],
     damage: u32,
+    direction: Vec2,
+    distance: f32,
+    max_distance: f32,
+    chain_targets: Vec<Entity>,
+    pierced_enemies: Vec<Entity>,
 }
 
 impl ProjectileVariant {
     pub fn get_config(&self) -> ProjectileVariantConfig {
         match self {
-            ProjectileVariant::Bullet => ProjectileVariantConfig {
-                duration: Duration::from_secs_f32(0.1),
+            ProjectileVariant::Bullet { .. } => ProjectileVariantConfig {
                 sprite_scale: Vec3::new(0.5, 0.5, 1.0),
             },
             ProjectileVariant::Rocket { .. } => ProjectileVariantConfig {
-                duration: Duration::from_secs_f32(0.2),
                 sprite_scale: Vec3::new(0.75, 0.75, 1.0),
             },
+            ProjectileVariant::Piercing { .. } => ProjectileVariantConfig {
+                sprite_scale: Vec3::new(0.5, 0.75, 1.0),
+            },
+            ProjectileVariant::Chain { .. } => ProjectileVariantConfig {
+                sprite_scale: Vec3::new(0.5, 0.5, 1.0),
+            },
         }
     }
 }
@@ -68,19 +222,91 @@ impl Projectile {
     pub fn new(variant: ProjectileVariant, target: Entity, damage: u32) -> Self {
         Self {
             variant,
+            source: None,
             target,
+            target_domains: &[],
             damage,
+            direction: Vec2::X,
+            distance: 0.0,
+            max_distance: 0.0,
+            chain_targets: Vec::new(),
+            pierced_enemies: Vec::new(),
         }
     }
+    pub fn with_source(mut self, source: Entity) -> Self {
+        self.source = Some(source);
+        self
+    }
+    pub fn with_target_domains(mut self, target_domains: &'static [EnemyDomain]) -> Self {
+        self.target_domains = target_domains;
+        self
+    }
+    pub fn with_direction(mut self, direction: Vec2) -> Self {
+        self.direction = direction.normalize_or(Vec2::X);
+        self
+    }
+    pub fn with_max_distance(mut self, max_distance: f32) -> Self {
+        self.max_distance = max_distance;
+        self
+    }
+    pub fn with_chain_targets(mut self, chain_targets: Vec<Entity>) -> Self {
+        self.chain_targets = chain_targets;
+        self
+    }
+    pub fn get_chain_target_entities(&self) -> &Vec<Entity> {
+        &self.chain_targets
+    }
     pub fn get_variant(&self) -> ProjectileVariant {
         self.variant
     }
+    pub fn get_source(&self) -> Option<Entity> {
+        self.source
+    }
     pub fn get_target(&self) -> Entity {
         self.target
     }
+    pub fn set_target(&mut self, target: Entity) {
+        self.target = target;
+    }
+    pub fn can_hit(&self, domain: EnemyDomain) -> bool {
+        self.target_domains.contains(&domain)
+    }
     pub fn get_damage(&self) -> u32 {
         self.damage
     }
+    pub fn get_damage_to(&self, enemy_entity: Entity) -> u32 {
+        if self.target == enemy_entity {
+            return self.damage;
+        }
+        self.chain_targets
+            .iter()
+            .position(|chain_entity| *chain_entity == enemy_entity)
+            .map_or(0, |jump| self.get_chain_damage(self.damage, jump + 1))
+    }
+    pub fn has_pierced(&self, enemy_entity: Entity) -> bool {
+        self.pierced_enemies.contains(&enemy_entity)
+    }
+    pub fn add_pierced_enemy(&mut self, enemy_entity: Entity) {
+        self.pierced_enemies.push(enemy_entity);
+    }
+    pub fn get_pierced_count(&self) -> usize {
+        self.pierced_enemies.len()
+    }
+    pub fn get_direction(&self) -> Vec2 {
+        self.direction
+    }
+    pub fn set_direction(&mut self, direction: Vec2) {
+        self.direction = direction.try_normalize().unwrap_or(self.direction);
+    }
+    pub fn get_speed(&self) -> f32 {
+        self.get_kinematics().get_speed()
+    }
+    pub fn add_distance(&mut self, distance: f32) {
+        self.distance += distance;
+    }
+    pub fn is_out_of_range(&self) -> bool {
+        self.distance >= self.max_distance
+    }
 }
 
 impl Deref for Projectile {
@@ -134,56 +360,290 @@ fn update_projectile(
     mut commands: Commands,
     game_tilemap: Single<Entity, With<GameTilemap>>,
     mut projectiles: Query<
+        (Entity, &mut Projectile, &mut TilePosition, &mut Transform),
+        With<Projectile>,
+    >,
+    mut enemies: Query<
         (
-            &Projectile,
             Entity,
+            &Enemy,
+            &mut EnemyHealth,
+            &mut EnemyStatusEffects,
             &TileMovement,
-            &mut TilePosition,
-            &mut Transform,
+            &TilePosition,
         ),
-        With<Projectile>,
+        (With<Enemy>, Without<Projectile>),
     >,
-    mut enemies: Query<(&mut EnemyHealth, &TilePosition), (With<Enemy>, Without<Projectile>)>,
+    mut soldiers: Query<&mut Soldier>,
+    game_speed: Res<GameSpeed>,
+    time: Res<Time>,
 ) {
     for (
-        projectile,
         projectile_entity,
-        projectile_movement,
+        mut projectile,
         mut projectile_tile_position,
         mut projectile_transform,
     ) in projectiles.iter_mut()
     {
-        if projectile_movement.get_progress() >= 1.0 {
-            commands.entity(projectile_entity).despawn();
-            if let Some(radius) = projectile.get_blast_radius() {
-                for (mut enemy_health, enemy_tile_position) in enemies.iter_mut() {
-                    if enemy_tile_position
-                        .as_vec2()
-                        .distance(projectile_tile_position.as_vec2())
-                        <= radius
-                    {
-                        enemy_health.damage(projectile.get_damage());
-                    }
+        let previous_position = projectile_tile_position.as_vec2();
+
+        if projectile.get_kinematics().is_homing() == true {
+            let target_position = enemies
+                .get(projectile.get_target())
+                .ok()
+                .filter(|(_, _, enemy_health, ..)| enemy_health.get_current() > 0)
+                .map(|(.., enemy_tile_position)| enemy_tile_position.as_vec2());
+
+            let target_position = target_position.or_else(|| {
+                enemies
+                    .iter()
+                    .filter(|(_, enemy, enemy_health, _, enemy_movement, _)| {
+                        enemy_movement.get_progress() > 0.0
+                            && enemy_health.get_current() > 0
+                            && projectile.can_hit(enemy.get_domain())
+                    })
+                    .min_by(|(.., enemy_a_tile_position), (.., enemy_b_tile_position)| {
+                        enemy_a_tile_position
+                            .as_vec2()
+                            .distance(previous_position)
+                            .total_cmp(&enemy_b_tile_position.as_vec2().distance(previous_position))
+                    })
+                    .map(|(enemy_entity, .., enemy_tile_position)| {
+                        projectile.set_target(enemy_entity);
+                        enemy_tile_position.as_vec2()
+                    })
+            });
+
+            if let Some(target_position) = target_position {
+                projectile.set_direction(target_position - previous_position);
+            }
+        }
+
+        let step = projectile.get_speed() * time.delta_secs() * game_speed.as_f32();
+        let position = previous_position + projectile.get_direction() * step;
+        projectile.add_distance(step);
+
+        if let (Some(hit_width), Some(max_pierce)) =
+            (projectile.get_hit_width(), projectile.get_max_pierce())
+        {
+            let mut pierced_enemies = enemies
+                .iter()
+                .filter(
+                    |(
+                        enemy_entity,
+                        enemy,
+                        enemy_health,
+                        _,
+                        enemy_movement,
+                        enemy_tile_position,
+                    )| {
+                        enemy_movement.get_progress() > 0.0
+                            && enemy_health.get_current() > 0
+                            && projectile.can_hit(enemy.get_domain())
+                            && projectile.has_pierced(*enemy_entity) == false
+                            && distance_to_segment(
+                                enemy_tile_position.as_vec2(),
+                                previous_position,
+                                position,
+                            ) <= hit_width / 2.0
+                    },
+                )
+                .map(|(enemy_entity, .., enemy_tile_position)| {
+                    (enemy_entity, enemy_tile_position.as_vec2())
+                })
+                .collect::<Vec<_>>();
+
+            pierced_enemies.sort_unstable_by(|(_, enemy_a_position), (_, enemy_b_position)| {
+                enemy_a_position
+                    .distance(previous_position)
+                    .total_cmp(&enemy_b_position.distance(previous_position))
+            });
+
+            for (enemy_entity, _) in pierced_enemies {
+                if projectile.get_pierced_count() >= max_pierce {
+                    break;
                 }
-                commands.entity(game_tilemap.entity()).with_child((
-                    ProjectileBlast::new(radius),
-                    TilePosition::from_vec2(projectile_tile_position.as_vec2()),
-                ));
-            } else if let Ok((mut enemy_health, _enemy_tile_position)) =
-                enemies.get_mut(projectile.get_target())
+                if let Ok((_, enemy, mut enemy_health, mut enemy_status_effects, _, _)) =
+                    enemies.get_mut(enemy_entity)
+                {
+                    damage_enemy(
+                        &projectile,
+                        projectile.get_damage(),
+                        enemy,
+                        &mut enemy_health,
+                        &mut enemy_status_effects,
+                        &mut soldiers,
+                    );
+                }
+                projectile.add_pierced_enemy(enemy_entity);
+            }
+
+            if projectile.get_pierced_count() >= max_pierce || projectile.is_out_of_range() == true
             {
-                enemy_health.damage(projectile.get_damage());
+                commands.entity(projectile_entity).despawn();
+                continue;
             }
+
+            projectile_tile_position.set_from_vec2(position);
+
+            let direction = projectile.get_direction();
+            projectile_transform.rotation =
+                Quat::from_rotation_z(direction.x.atan2(direction.y) - FRAC_PI_2);
+            projectile_transform.scale = projectile.get_config().get_sprite_scale();
             continue;
         }
-        projectile_tile_position.set_from_vec2(projectile_movement.get_position());
 
-        let direction = (projectile_movement.get_position()
-            - projectile_movement.get_previous_position())
-        .normalize_or_zero();
+        let hit_enemy = enemies
+            .iter()
+            .filter(
+                |(_, enemy, enemy_health, _, enemy_movement, enemy_tile_position)| {
+                    enemy_movement.get_progress() > 0.0
+                        && enemy_health.get_current() > 0
+                        && projectile.can_hit(enemy.get_domain())
+                        && distance_to_segment(
+                            enemy_tile_position.as_vec2(),
+                            previous_position,
+                            position,
+                        ) <= PROJECTILE_HIT_RADIUS
+                },
+            )
+            .min_by(|(.., enemy_a_tile_position), (.., enemy_b_tile_position)| {
+                enemy_a_tile_position
+                    .as_vec2()
+                    .distance(previous_position)
+                    .total_cmp(&enemy_b_tile_position.as_vec2().distance(previous_position))
+            })
+            .map(|(enemy_entity, .., enemy_tile_position)| {
+                (enemy_entity, enemy_tile_position.as_vec2())
+            });
+
+        if hit_enemy.is_none() && projectile.is_out_of_range() == false {
+            projectile_tile_position.set_from_vec2(position);
+
+            let direction = projectile.get_direction();
+            projectile_transform.rotation =
+                Quat::from_rotation_z(direction.x.atan2(direction.y) - FRAC_PI_2);
+            projectile_transform.scale = projectile.get_config().get_sprite_scale();
+            continue;
+        }
+
+        commands.entity(projectile_entity).despawn();
+
+        if let Some(radius) = projectile.get_blast_radius() {
+            let blast_position = hit_enemy.map_or(position, |(_, enemy_position)| enemy_position);
+
+            for (
+                _,
+                enemy,
+                mut enemy_health,
+                mut enemy_status_effects,
+                enemy_movement,
+                enemy_tile_position,
+            ) in enemies.iter_mut()
+            {
+                if enemy_tile_position.as_vec2().distance(blast_position) <= radius
+                    && projectile.can_hit(enemy.get_domain())
+                    && enemy_movement.get_progress() > 0.0
+                {
+                    damage_enemy(
+                        &projectile,
+                        projectile.get_damage(),
+                        enemy,
+                        &mut enemy_health,
+                        &mut enemy_status_effects,
+                        &mut soldiers,
+                    );
+                }
+            }
+            commands.entity(game_tilemap.entity()).with_child((
+                ProjectileBlast::new(radius),
+                TilePosition::from_vec2(blast_position),
+            ));
+        } else if let Some((enemy_entity, enemy_position)) = hit_enemy {
+            let chain_targets = projectile
+                .get_chain_target_entities()
+                .iter()
+                .enumerate()
+                .filter(|(_, chain_entity)| **chain_entity != enemy_entity)
+                .filter_map(|(jump, chain_entity)| {
+                    enemies
+                        .get(*chain_entity)
+                        .ok()
+                        .filter(|(_, _, enemy_health, _, enemy_movement, _)| {
+                            enemy_movement.get_progress() > 0.0 && enemy_health.get_current() > 0
+                        })
+                        .map(|(.., chain_tile_position)| {
+                            (jump + 1, *chain_entity, chain_tile_position.as_vec2())
+                        })
+                })
+                .collect::<Vec<_>>();
+
+            let mut beam_start = enemy_position;
+            for (jump, target_entity, target_position) in
+                std::iter::once((0, enemy_entity, enemy_position)).chain(chain_targets)
+            {
+                if let Ok((_, enemy, mut enemy_health, mut enemy_status_effects, _, _)) =
+                    enemies.get_mut(target_entity)
+                {
+                    damage_enemy(
+                        &projectile,
+                        projectile.get_chain_damage(projectile.get_damage(), jump),
+                        enemy,
+                        &mut enemy_health,
+                        &mut enemy_status_effects,
+                        &mut soldiers,
+                    );
+                }
+                if jump > 0 {
+                    commands
+                        .entity(game_tilemap.entity())
+                        .with_child(ChainBeam::new(beam_start, target_position));
+                }
+                beam_start = target_position;
+            }
+        }
+    }
+}
 
-        projectile_transform.rotation =
-            Quat::from_rotation_z(direction.x.atan2(direction.y) - FRAC_PI_2);
-        projectile_transform.scale = projectile.get_config().get_sprite_scale();
+fn damage_enemy(
+    projectile: &Projectile,
+    damage: u32,
+    enemy: &Enemy,
+    enemy_health: &mut EnemyHealth,
+    enemy_status_effects: &mut EnemyStatusEffects,
+    soldiers: &mut Query<&mut Soldier>,
+) {
+    let previous_health = enemy_health.get_current();
+
+    enemy_health.damage(
+        enemy
+            .get_config()
+            .get_armor()
+            .resolve_damage(damage, projectile.get_damage_type()),
+    );
+    if let Some(status_effect) = projectile.get_status_effect() {
+        enemy_status_effects.apply(match projectile.get_source() {
+            Some(source) => status_effect.with_source(source),
+            None => status_effect,
+        });
     }
+
+    if let Some(mut soldier) = projectile
+        .get_source()
+        .and_then(|source| soldiers.get_mut(source).ok())
+    {
+        soldier.record_damage(previous_health, enemy_health.get_current());
+    }
+}
+
+fn distance_to_segment(point: Vec2, segment_start: Vec2, segment_end: Vec2) -> f32 {
+    let segment = segment_end - segment_start;
+    let segment_length_squared = segment.length_squared();
+
+    if segment_length_squared < 1e-6 {
+        return point.distance(segment_start);
+    }
+
+    let t = ((point - segment_start).dot(segment) / segment_length_squared).clamp(0.0, 1.0);
+    point.distance(segment_start + segment * t)
 }
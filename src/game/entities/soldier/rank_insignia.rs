@@ -0,0 +1,127 @@
+use bevy::prelude::*;
+
+use crate::game::{
+    GameState,
+    assets::images::entity::{EntityAssets, UtilSpriteVariant},
+    entities::soldier::Soldier,
+};
+
+#[derive(Component, Clone)]
+#[require(Transform, Visibility::Hidden)]
+pub struct RankInsignia {
+    soldier_entity: Entity,
+    rank: usize,
+}
+
+impl RankInsignia {
+    pub fn new(soldier_entity: Entity) -> Self {
+        Self {
+            soldier_entity,
+            rank: 0,
+        }
+    }
+    pub fn get_soldier_entity(&self) -> Entity {
+        self.soldier_entity
+    }
+    pub fn get_rank(&self) -> usize {
+        self.rank
+    }
+    pub fn set_rank(&mut self, rank: usize) {
+        self.rank = rank;
+    }
+}
+
+pub struct RankInsigniaPlugin;
+
+impl Plugin for RankInsigniaPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PreUpdate, init_rank_insignia);
+        app.add_systems(PostUpdate, despawn_rank_insignia);
+
+        app.add_systems(
+            Update,
+            update_rank_insignia.run_if(in_state(GameState::InGame)),
+        );
+    }
+}
+
+fn init_rank_insignia(
+    mut commands: Commands,
+    rank_insignias: Query<Entity, Added<RankInsignia>>,
+    entity_assets: Option<Res<EntityAssets>>,
+) {
+    for rank_insignia_entity in rank_insignias.iter() {
+        if let Some(entity_assets) = &entity_assets {
+            commands.entity(rank_insignia_entity).insert(Sprite {
+                image: entity_assets.tilemap.clone(),
+                texture_atlas: Some(TextureAtlas {
+                    layout: entity_assets.tilemap_layout.clone(),
+                    index: UtilSpriteVariant::RankInsignia1 as usize,
+                }),
+                ..default()
+            });
+        }
+    }
+}
+
+fn despawn_rank_insignia(
+    mut commands: Commands,
+    rank_insignias: Query<(Entity, &RankInsignia)>,
+    mut removed_soldiers: RemovedComponents<Soldier>,
+) {
+    for removed_soldier_entity in removed_soldiers.read() {
+        for (rank_insignia_entity, rank_insignia) in rank_insignias.iter() {
+            if rank_insignia.get_soldier_entity() == removed_soldier_entity {
+                commands.entity(rank_insignia_entity).despawn();
+            }
+        }
+    }
+}
+
+fn update_rank_insignia(
+    soldiers: Query<(&Soldier, &Transform)>,
+    mut rank_insignias: Query<
+        (
+            &mut RankInsignia,
+            &mut Sprite,
+            &mut Visibility,
+            &mut Transform,
+        ),
+        Without<Soldier>,
+    >,
+) {
+    for (
+        mut rank_insignia,
+        mut rank_insignia_sprite,
+        mut rank_insignia_visibility,
+        mut rank_insignia_transform,
+    ) in rank_insignias.iter_mut()
+    {
+        let Ok((soldier, soldier_transform)) = soldiers.get(rank_insignia.get_soldier_entity())
+        else {
+            continue;
+        };
+
+        rank_insignia_transform.translation = soldier_transform.translation.with_z(3.0);
+
+        let rank = soldier.get_rank();
+        if rank == rank_insignia.get_rank() {
+            continue;
+        }
+
+        if let Some(texture_atlas) = rank_insignia_sprite.texture_atlas.as_mut() {
+            texture_atlas.index = match rank {
+                1 => UtilSpriteVariant::RankInsignia1,
+                2 => UtilSpriteVariant::RankInsignia2,
+                _ => UtilSpriteVariant::RankInsignia3,
+            } as usize;
+        }
+        *rank_insignia_visibility = if rank > 0 {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+
+        rank_insignia.set_rank(rank);
+    }
+}
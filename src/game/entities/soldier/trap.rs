@@ -0,0 +1,155 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::game::{
+    GameState, GameTilemap,
+    entities::{
+        enemy::{Enemy, health::EnemyHealth},
+        soldier::{Soldier, config::SoldierAttack, projectile_blast::ProjectileBlast},
+        tile::{movement::TileMovement, position::TilePosition},
+    },
+};
+
+#[derive(Component, Clone, Default)]
+pub struct SoldierTrap {
+    triggered_enemies: Vec<Entity>,
+}
+
+impl SoldierTrap {
+    pub fn is_triggered_by(&self, enemy_entity: Entity) -> bool {
+        self.triggered_enemies.contains(&enemy_entity)
+    }
+    pub fn add_triggered_enemy(&mut self, enemy_entity: Entity) {
+        self.triggered_enemies.push(enemy_entity);
+    }
+    pub fn retain_triggered_enemies(&mut self, enemy_entities: &[Entity]) {
+        self.triggered_enemies
+            .retain(|enemy_entity| enemy_entities.contains(enemy_entity));
+    }
+}
+
+pub struct SoldierTrapPlugin;
+
+impl Plugin for SoldierTrapPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            update_soldier_trap.run_if(in_state(GameState::InGame)),
+        );
+    }
+}
+
+fn update_soldier_trap(
+    mut commands: Commands,
+    game_tilemap: Single<Entity, With<GameTilemap>>,
+    mut soldiers: Query<(Entity, &mut Soldier, &mut SoldierTrap, &TilePosition)>,
+    mut enemies: Query<
+        (
+            Entity,
+            &Enemy,
+            &mut EnemyHealth,
+            &TileMovement,
+            &TilePosition,
+        ),
+        Without<Soldier>,
+    >,
+) {
+    for (soldier_entity, mut soldier, mut soldier_trap, soldier_tile_position) in
+        soldiers.iter_mut()
+    {
+        let SoldierAttack::Trap {
+            damage_type,
+            blast_radius,
+            ..
+        } = soldier.get_config().get_attack()
+        else {
+            continue;
+        };
+
+        let enemies_on_tile = enemies
+            .iter()
+            .filter(|(_, enemy, _, enemy_movement, enemy_tile_position)| {
+                enemy_movement.get_progress() > 0.0
+                    && soldier.get_config().can_target(enemy.get_domain())
+                    && enemy_tile_position.as_vec2().round() == soldier_tile_position.as_vec2()
+            })
+            .map(|(enemy_entity, ..)| enemy_entity)
+            .collect::<Vec<_>>();
+
+        soldier_trap.retain_triggered_enemies(&enemies_on_tile);
+
+        if soldier.get_cooldown() > Duration::ZERO {
+            continue;
+        }
+
+        let entered_enemies = enemies_on_tile
+            .into_iter()
+            .filter(|enemy_entity| soldier_trap.is_triggered_by(*enemy_entity) == false)
+            .collect::<Vec<_>>();
+
+        if entered_enemies.is_empty() {
+            continue;
+        }
+
+        if let Some(blast_radius) = blast_radius {
+            for (_, enemy, mut enemy_health, enemy_movement, enemy_tile_position) in
+                enemies.iter_mut()
+            {
+                if enemy_movement.get_progress() <= 0.0
+                    || soldier.get_config().can_target(enemy.get_domain()) == false
+                    || enemy_tile_position
+                        .as_vec2()
+                        .distance(soldier_tile_position.as_vec2())
+                        > blast_radius
+                {
+                    continue;
+                }
+                let previous_health = enemy_health.get_current();
+
+                enemy_health.damage(
+                    enemy
+                        .get_config()
+                        .get_armor()
+                        .resolve_damage(soldier.get_damage(), damage_type),
+                );
+                soldier.record_damage(previous_health, enemy_health.get_current());
+            }
+            commands.entity(game_tilemap.entity()).with_child((
+                ProjectileBlast::new(blast_radius),
+                TilePosition::from_vec2(soldier_tile_position.as_vec2()),
+            ));
+
+            for enemy_entity in entered_enemies {
+                soldier_trap.add_triggered_enemy(enemy_entity);
+            }
+            soldier.use_charge();
+        } else {
+            for enemy_entity in entered_enemies {
+                if soldier.get_remaining_charges().unwrap_or_default() == 0 {
+                    break;
+                }
+                let Ok((_, enemy, mut enemy_health, _, _)) = enemies.get_mut(enemy_entity) else {
+                    continue;
+                };
+                let previous_health = enemy_health.get_current();
+
+                enemy_health.damage(
+                    enemy
+                        .get_config()
+                        .get_armor()
+                        .resolve_damage(soldier.get_damage(), damage_type),
+                );
+                soldier.record_damage(previous_health, enemy_health.get_current());
+                soldier_trap.add_triggered_enemy(enemy_entity);
+                soldier.use_charge();
+            }
+        }
+
+        soldier.update_cooldown();
+
+        if soldier.get_remaining_charges().unwrap_or_default() == 0 {
+            commands.entity(soldier_entity).despawn();
+        }
+    }
+}
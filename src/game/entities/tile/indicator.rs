@@ -3,17 +3,14 @@ use bevy::prelude::*;
 use crate::game::{
     GameTilemap,
     entities::{
-        soldier::Soldier,
+        soldier::{Soldier, SoldierVariant},
         tile::{
             position::TilePosition,
             sprite::{TileSprite, TileSpriteVariant, UtilVariant},
         },
-        tilemap::{
-            Tilemap,
-            tile::{TilemapTile, TilemapTileVariant},
-        },
+        tilemap::{Tilemap, tile::TilemapTile},
     },
-    input::SelectedTile,
+    input::{SelectedAbility, SelectedTile},
 };
 
 #[derive(Component)]
@@ -28,7 +25,8 @@ impl Plugin for TileIndicatorPlugin {
 
         app.add_systems(
             PostUpdate,
-            update_tile_indicator.run_if(resource_changed::<SelectedTile>),
+            update_tile_indicator
+                .run_if(resource_changed::<SelectedTile>.or(resource_changed::<SelectedAbility>)),
         );
     }
 }
@@ -51,6 +49,7 @@ fn update_tile_indicator(
     tiles: Query<&TilemapTile>,
     soldiers: Query<&TilePosition, (With<Soldier>, Without<TileIndicator>)>,
     selected_tile: Res<SelectedTile>,
+    selected_ability: Res<SelectedAbility>,
 ) {
     for (mut tile_indicator_tile_position, mut tile_indicator_sprite) in tile_indicator.iter_mut() {
         tile_indicator_tile_position.set_from_vec2(selected_tile.tile_position.as_vec2());
@@ -63,9 +62,17 @@ fn update_tile_indicator(
             game_tilemap.get_tile(selected_tile.tile_position.as_ivec2())
         {
             if let Ok(selected_tile) = tiles.get(selected_tile_entity) {
-                if soldier_found {
+                if let Some(selected_ability_variant) = selected_ability.variant {
+                    if selected_ability_variant
+                        .can_target(selected_tile.get_variant(), soldier_found)
+                    {
+                        tile_indicator_sprite.color = Color::srgba(1.0, 0.5, 0.0, 0.75);
+                    } else {
+                        tile_indicator_sprite.color = Color::srgba(1.0, 0.0, 0.0, 0.75);
+                    }
+                } else if soldier_found {
                     tile_indicator_sprite.color = Color::srgba(1.0, 1.0, 0.0, 0.75);
-                } else if selected_tile.get_variant() == TilemapTileVariant::Ground {
+                } else if SoldierVariant::is_placement_tile(selected_tile.get_variant()) {
                     tile_indicator_sprite.color = Color::srgba(1.0, 1.0, 1.0, 0.75);
                 } else {
                     tile_indicator_sprite.color = Color::srgba(1.0, 0.0, 0.0, 0.75);
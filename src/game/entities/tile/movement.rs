@@ -15,6 +15,7 @@ pub struct TileMovement {
     duration: Duration,
     delay: Duration,
     speed: f32,
+    speed_multiplier: f32,
     progress: f32,
     elapsed_time: Duration,
 }
@@ -30,6 +31,7 @@ impl Default for TileMovement {
             duration: Duration::ZERO,
             delay: Duration::ZERO,
             speed: 0.0,
+            speed_multiplier: 1.0,
             progress: 0.0,
             elapsed_time: Duration::ZERO,
         }
@@ -68,6 +70,49 @@ impl TileMovement {
         tile_movement.update_current_position();
         tile_movement
     }
+    pub fn from_speed(path: Vec<Vec2>, speed: f32, delay: Option<Duration>) -> Self {
+        let total_length = path
+            .windows(2)
+            .map(|segment| (segment[1] - segment[0]).length())
+            .sum::<f32>();
+
+        Self::new(
+            path,
+            Duration::from_secs_f32(total_length / speed.max(f32::EPSILON)),
+            delay,
+        )
+    }
+    pub fn with_progress(mut self, progress: f32) -> Self {
+        self.set_progress(progress);
+        self.previous_position = self.position;
+        self
+    }
+    pub fn get_path(&self) -> &Vec<Vec2> {
+        &self.path
+    }
+    pub fn set_path(&mut self, path: Vec<Vec2>) {
+        let started = self.progress > 0.0;
+        let position = self.position;
+        let speed_multiplier = self.speed_multiplier;
+
+        *self = Self::from_speed(
+            path,
+            self.speed,
+            Some(self.delay.saturating_sub(self.elapsed_time)),
+        );
+        self.speed_multiplier = speed_multiplier;
+
+        if started == true {
+            self.set_progress(f32::EPSILON);
+        }
+        self.previous_position = position;
+    }
+    pub fn get_length(&self) -> f32 {
+        *self.path_cumulative_lengths.last().unwrap_or(&0.0)
+    }
+    pub fn get_remaining_distance(&self) -> f32 {
+        self.get_length() * (1.0 - self.progress)
+    }
     pub fn get_position(&self) -> Vec2 {
         self.position
     }
@@ -80,17 +125,37 @@ impl TileMovement {
     pub fn get_speed(&self) -> f32 {
         self.speed
     }
+    pub fn set_speed(&mut self, speed: f32) {
+        let started = self.progress > 0.0;
+
+        self.speed = speed.max(f32::EPSILON);
+        self.duration = Duration::from_secs_f32(self.get_length() / self.speed);
+
+        if started == true {
+            self.set_progress(self.progress);
+        }
+    }
+    pub fn get_speed_multiplier(&self) -> f32 {
+        self.speed_multiplier
+    }
+    pub fn set_speed_multiplier(&mut self, speed_multiplier: f32) {
+        self.speed_multiplier = speed_multiplier.max(0.0);
+    }
     pub fn get_progress(&self) -> f32 {
         self.progress
     }
-    #[allow(unused)]
     pub fn set_progress(&mut self, progress: f32) {
         self.progress = progress.clamp(0.0, 1.0);
-        self.elapsed_time = Duration::from_secs_f32(self.duration.as_secs_f32() * self.progress);
+        self.elapsed_time =
+            self.delay + Duration::from_secs_f32(self.duration.as_secs_f32() * self.progress);
         self.update_current_position();
     }
     pub fn update_progress(&mut self, delta_time: Duration) {
-        self.elapsed_time += delta_time;
+        self.elapsed_time += if self.elapsed_time < self.delay {
+            delta_time
+        } else {
+            delta_time.mul_f32(self.speed_multiplier)
+        };
         self.progress = ((self.elapsed_time.as_secs_f32() - self.delay.as_secs_f32())
             / self.duration.as_secs_f32())
         .clamp(0.0, 1.0);
@@ -105,8 +170,7 @@ impl TileMovement {
         self.position = self.position_at_progress(self.progress);
     }
     pub fn position_at_progress(&self, progress: f32) -> Vec2 {
-        let target_distance =
-            self.path_cumulative_lengths.last().unwrap_or(&0.0) * progress.clamp(0.0, 1.0);
+        let target_distance = self.get_length() * progress.clamp(0.0, 1.0);
 
         let segment_index = self
             .path_cumulative_lengths
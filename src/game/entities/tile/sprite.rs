@@ -50,7 +50,9 @@ impl TileSpriteVariant {
     pub fn as_index(&self) -> usize {
         match self {
             TileSpriteVariant::Projectile(variant) => match variant {
-                ProjectileVariant::Bullet => ProjectileSpriteVariant::Bullet as usize,
+                ProjectileVariant::Bullet { .. }
+                | ProjectileVariant::Piercing { .. }
+                | ProjectileVariant::Chain { .. } => ProjectileSpriteVariant::Bullet as usize,
                 ProjectileVariant::Rocket { .. } => ProjectileSpriteVariant::Rocket as usize,
             },
             TileSpriteVariant::Soldier(variant) => {
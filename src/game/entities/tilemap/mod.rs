@@ -57,6 +57,66 @@ impl Tilemap {
     pub fn get_tile(&self, position: IVec2) -> Option<Entity> {
         self.tiles.get(&position).copied()
     }
+    pub fn is_line_of_sight_blocked(
+        &self,
+        tiles: &Query<&TilemapTile>,
+        from: Vec2,
+        to: Vec2,
+    ) -> bool {
+        let start = from.round().as_ivec2();
+        let end = to.round().as_ivec2();
+        let direction = to - from;
+
+        let step = IVec2::new(
+            if direction.x < 0.0 { -1 } else { 1 },
+            if direction.y < 0.0 { -1 } else { 1 },
+        );
+        let get_boundary = |origin: f32, direction: f32| -> (f32, f32) {
+            if direction == 0.0 {
+                return (f32::INFINITY, f32::INFINITY);
+            }
+            let delta = 1.0 / direction.abs();
+            let cell_origin = origin + 0.5;
+            let offset = if direction > 0.0 {
+                cell_origin.floor() + 1.0 - cell_origin
+            } else {
+                cell_origin - cell_origin.floor()
+            };
+            (offset * delta, delta)
+        };
+        let (boundary_x, delta_x) = get_boundary(from.x, direction.x);
+        let (boundary_y, delta_y) = get_boundary(from.y, direction.y);
+        let delta = Vec2::new(delta_x, delta_y);
+        let mut boundary = Vec2::new(boundary_x, boundary_y);
+
+        let mut current = start;
+        while current != end {
+            if boundary.x < boundary.y {
+                if boundary.x > 1.0 {
+                    break;
+                }
+                current.x += step.x;
+                boundary.x += delta.x;
+            } else {
+                if boundary.y > 1.0 {
+                    break;
+                }
+                current.y += step.y;
+                boundary.y += delta.y;
+            }
+
+            if current != end
+                && self
+                    .get_tile(current)
+                    .and_then(|tile_entity| tiles.get(tile_entity).ok())
+                    .is_some_and(|tile| tile.get_variant().blocks_line_of_sight())
+            {
+                return true;
+            }
+        }
+
+        false
+    }
     pub fn get_update_required(&self) -> bool {
         self.update_required
     }
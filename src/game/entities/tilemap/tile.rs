@@ -1,8 +1,8 @@
 use bevy::prelude::*;
 
-use crate::game::entities::tile::position::TilePosition;
+use crate::game::entities::{enemy::EnemyDomain, tile::position::TilePosition};
 
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Default)]
 pub enum TilemapTileVariant {
     Ground,
     Flower,
@@ -10,9 +10,33 @@ pub enum TilemapTileVariant {
     Road,
     Bridge,
     Water,
+    #[default]
     Unknown,
 }
 
+impl TilemapTileVariant {
+    pub fn blocks_line_of_sight(&self) -> bool {
+        matches!(self, TilemapTileVariant::Tree)
+    }
+    pub fn is_open_ground(&self) -> bool {
+        matches!(
+            self,
+            TilemapTileVariant::Ground | TilemapTileVariant::Flower
+        )
+    }
+    pub fn is_traversable_by(&self, domain: EnemyDomain) -> bool {
+        match domain {
+            EnemyDomain::Ground => {
+                matches!(self, TilemapTileVariant::Road | TilemapTileVariant::Bridge)
+            }
+            EnemyDomain::Air => true,
+            EnemyDomain::Naval => {
+                matches!(self, TilemapTileVariant::Water | TilemapTileVariant::Bridge)
+            }
+        }
+    }
+}
+
 #[derive(Component, Clone, Copy)]
 #[require(TilePosition)]
 pub struct TilemapTile {
@@ -1,13 +1,20 @@
+use std::time::Duration;
+
 use bevy::{audio::PlaybackMode, input::touch::TouchPhase, prelude::*};
 use bevy_persistent::Persistent;
 
 use crate::game::{
-    assets::audio::ui::UiAudioAssets,
+    abilities::{PlayerAbilities, PlayerAbilityActivated, PlayerAbilityVariant},
+    assets::{audio::ui::UiAudioAssets, levels::Level},
     audio::{GameAudio, GameAudioVolume},
     camera::GameCamera,
     entities::{
+        enemy::{
+            Enemy,
+            route::{EnemyRoutes, get_blocked_tiles},
+        },
         soldier::{Soldier, SoldierVariant},
-        tile::position::TilePosition,
+        tile::{movement::TileMovement, position::TilePosition},
         tilemap::{
             Tilemap,
             tile::{TilemapTile, TilemapTileVariant},
@@ -18,16 +25,23 @@ use crate::game::{
     {GameState, GameTilemap},
 };
 
+const FOCUS_FIRE_DURATION: Duration = Duration::from_secs(5);
+
 pub struct GameInputPlugin;
 
 impl Plugin for GameInputPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<SelectedTile>();
         app.init_resource::<SelectedSoldier>();
+        app.init_resource::<SelectedAbility>();
 
         app.add_systems(
             Update,
-            (update_selected_tile, update_selected_soldier)
+            (
+                update_selected_tile,
+                update_selected_soldier,
+                update_selected_ability,
+            )
                 .chain()
                 .run_if(in_state(GameState::InGame)),
         );
@@ -44,6 +58,12 @@ pub struct SelectedTile {
 pub struct SelectedSoldier {
     pub variant: Option<SoldierVariant>,
     pub tile_position: TilePosition,
+    pub tile_variant: TilemapTileVariant,
+}
+
+#[derive(Resource, Default)]
+pub struct SelectedAbility {
+    pub variant: Option<PlayerAbilityVariant>,
 }
 
 fn update_selected_tile(
@@ -100,16 +120,21 @@ fn update_selected_soldier(
     mut commands: Commands,
     game_tilemap: Single<&Tilemap, With<GameTilemap>>,
     tiles: Query<&TilemapTile>,
-    soldiers: Query<(&Soldier, &TilePosition)>,
+    mut soldiers: Query<(&mut Soldier, &TilePosition)>,
+    enemies: Query<(Entity, &Enemy, &TileMovement, &TilePosition), Without<Soldier>>,
     selected_tile: Res<SelectedTile>,
     mut selected_soldier: ResMut<SelectedSoldier>,
+    selected_ability: Res<SelectedAbility>,
     game_waves: Res<GameWaves>,
     mouse_button_input: Res<ButtonInput<MouseButton>>,
     mut touch_events: MessageReader<TouchInput>,
     ui_interaction: Query<&Interaction, (Changed<Interaction>, With<Button>)>,
-    game_audio: Single<Entity, With<GameAudio>>,
-    game_audio_volume: Res<Persistent<GameAudioVolume>>,
-    ui_audio_assets: Res<UiAudioAssets>,
+    (game_audio, game_audio_volume, ui_audio_assets): (
+        Single<Entity, With<GameAudio>>,
+        Res<Persistent<GameAudioVolume>>,
+        Res<UiAudioAssets>,
+    ),
+    (selected_level, enemy_routes): (Res<Level>, Res<EnemyRoutes>),
     mut next_ui_state: ResMut<NextState<UiState>>,
     mut next_game_state: ResMut<NextState<GameState>>,
 ) {
@@ -121,6 +146,9 @@ fn update_selected_soldier(
     if mouse_pressed == false && touch_started == false {
         return;
     }
+    if selected_ability.variant.is_some() {
+        return;
+    }
     if selected_tile.tile_position.as_vec2() != selected_tile.previous_tile_position.as_vec2() {
         return;
     }
@@ -131,18 +159,37 @@ fn update_selected_soldier(
         return;
     }
 
-    if let Some(selected_tile_entity) =
+    let selected_tile_variant = if let Some(selected_tile_entity) =
         game_tilemap.get_tile(selected_tile.tile_position.as_ivec2())
     {
         if let Ok(selected_tile) = tiles.get(selected_tile_entity) {
-            if selected_tile.get_variant() != TilemapTileVariant::Ground {
-                return;
-            }
+            selected_tile.get_variant()
         } else {
             return;
         }
     } else {
         return;
+    };
+
+    let soldier_on_tile = soldiers.iter().any(|(_soldier, soldier_tile_position)| {
+        soldier_tile_position.as_vec2() == selected_tile.tile_position.as_vec2()
+    });
+
+    if soldier_on_tile == false && SoldierVariant::is_placement_tile(selected_tile_variant) == false
+    {
+        let focused =
+            focus_selected_soldier(&mut soldiers, &enemies, &selected_tile, &selected_soldier);
+        if focused == true {
+            commands.entity(game_audio.entity()).with_child((
+                AudioPlayer::new(ui_audio_assets.tilemap_click.clone()),
+                PlaybackSettings {
+                    mode: PlaybackMode::Remove,
+                    volume: game_audio_volume.get_sfx_volume(),
+                    ..default()
+                },
+            ));
+        }
+        return;
     }
 
     if let Some((soldier, _soldier_tile_position)) =
@@ -153,11 +200,34 @@ fn update_selected_soldier(
         selected_soldier.variant = Some(soldier.get_variant());
         next_ui_state.set(UiState::SoldierInfo);
     } else {
+        if selected_level.is_maze() == true
+            && selected_tile_variant.is_open_ground() == true
+            && enemy_routes.is_blocked_by(
+                &selected_level,
+                &get_blocked_tiles(soldiers.iter()),
+                selected_tile.tile_position.as_vec2().round().as_ivec2(),
+                &enemies
+                    .iter()
+                    .filter(|(_, _, enemy_movement, _)| {
+                        enemy_movement.get_progress() > 0.0 && enemy_movement.get_progress() < 1.0
+                    })
+                    .map(|(_, enemy, _, enemy_tile_position)| {
+                        (
+                            enemy_tile_position.as_vec2().round().as_ivec2(),
+                            enemy.get_domain(),
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            ) == true
+        {
+            return;
+        }
         selected_soldier.variant = None;
         next_ui_state.set(UiState::SoldierSelect);
     }
 
     selected_soldier.tile_position = selected_tile.tile_position;
+    selected_soldier.tile_variant = selected_tile_variant;
     next_game_state.set(GameState::Pause);
 
     commands.entity(game_audio.entity()).with_child((
@@ -169,3 +239,121 @@ fn update_selected_soldier(
         },
     ));
 }
+
+fn focus_selected_soldier(
+    soldiers: &mut Query<(&mut Soldier, &TilePosition)>,
+    enemies: &Query<(Entity, &Enemy, &TileMovement, &TilePosition), Without<Soldier>>,
+    selected_tile: &SelectedTile,
+    selected_soldier: &SelectedSoldier,
+) -> bool {
+    let Some((enemy_entity, enemy, _, enemy_tile_position)) =
+        enemies
+            .iter()
+            .find(|(_, _, enemy_movement, enemy_tile_position)| {
+                enemy_movement.get_progress() > 0.0
+                    && enemy_tile_position.as_vec2().round()
+                        == selected_tile.tile_position.as_vec2()
+            })
+    else {
+        return false;
+    };
+    let Some((mut soldier, soldier_tile_position)) =
+        soldiers
+            .iter_mut()
+            .find(|(soldier, soldier_tile_position)| {
+                selected_soldier.variant == Some(soldier.get_variant())
+                    && soldier_tile_position.as_vec2() == selected_soldier.tile_position.as_vec2()
+            })
+    else {
+        return false;
+    };
+
+    if soldier.get_config().get_attack().is_passive() == true
+        || soldier.get_config().can_target(enemy.get_domain()) == false
+        || soldier_tile_position
+            .as_vec2()
+            .distance(enemy_tile_position.as_vec2())
+            > soldier.get_fire_radius()
+    {
+        return false;
+    }
+    soldier.set_focus_target(enemy_entity, FOCUS_FIRE_DURATION);
+
+    true
+}
+
+fn update_selected_ability(
+    mut commands: Commands,
+    game_tilemap: Single<&Tilemap, With<GameTilemap>>,
+    tiles: Query<&TilemapTile>,
+    soldiers: Query<&TilePosition, With<Soldier>>,
+    selected_tile: Res<SelectedTile>,
+    mut selected_ability: ResMut<SelectedAbility>,
+    player_abilities: Res<PlayerAbilities>,
+    mouse_button_input: Res<ButtonInput<MouseButton>>,
+    mut touch_events: MessageReader<TouchInput>,
+    ui_interaction: Query<&Interaction, (Changed<Interaction>, With<Button>)>,
+    mut player_ability_activated_events: MessageWriter<PlayerAbilityActivated>,
+    game_audio: Single<Entity, With<GameAudio>>,
+    game_audio_volume: Res<Persistent<GameAudioVolume>>,
+    ui_audio_assets: Res<UiAudioAssets>,
+) {
+    let Some(variant) = selected_ability.variant else {
+        return;
+    };
+
+    if mouse_button_input.just_pressed(MouseButton::Right) {
+        selected_ability.variant = None;
+        return;
+    }
+
+    let mouse_pressed = mouse_button_input.just_pressed(MouseButton::Left);
+    let touch_started = touch_events
+        .read()
+        .any(|touch| touch.phase == TouchPhase::Started);
+
+    if mouse_pressed == false && touch_started == false {
+        return;
+    }
+    if selected_tile.tile_position.as_vec2() != selected_tile.previous_tile_position.as_vec2() {
+        return;
+    }
+    if ui_interaction.is_empty() == false {
+        return;
+    }
+    if player_abilities.is_ready(variant) == false {
+        selected_ability.variant = None;
+        return;
+    }
+
+    let Some(selected_tile_variant) = game_tilemap
+        .get_tile(selected_tile.tile_position.as_ivec2())
+        .and_then(|selected_tile_entity| tiles.get(selected_tile_entity).ok())
+        .map(|selected_tile| selected_tile.get_variant())
+    else {
+        return;
+    };
+
+    let soldier_found = soldiers.iter().any(|soldier_tile_position| {
+        soldier_tile_position.as_vec2() == selected_tile.tile_position.as_vec2()
+    });
+
+    if variant.can_target(selected_tile_variant, soldier_found) == false {
+        return;
+    }
+
+    player_ability_activated_events.write(PlayerAbilityActivated {
+        variant,
+        tile_position: selected_tile.tile_position,
+    });
+    selected_ability.variant = None;
+
+    commands.entity(game_audio.entity()).with_child((
+        AudioPlayer::new(ui_audio_assets.tilemap_click.clone()),
+        PlaybackSettings {
+            mode: PlaybackMode::Remove,
+            volume: game_audio_volume.get_sfx_volume(),
+            ..default()
+        },
+    ));
+}
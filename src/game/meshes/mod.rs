@@ -1,2 +1,3 @@
 pub mod annular_segment;
 pub mod rounded_rectangle;
+pub mod tile_grid;
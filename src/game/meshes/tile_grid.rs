@@ -0,0 +1,67 @@
+use bevy::{
+    asset::RenderAssetUsages,
+    mesh::{Indices, PrimitiveTopology},
+    prelude::*,
+};
+
+pub struct TileGrid;
+
+impl TileGrid {
+    pub fn new(tile_positions: &[Vec2], tile_size: f32) -> Mesh {
+        let mut mesh = Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::default(),
+        );
+        Self::update(&mut mesh, tile_positions, tile_size);
+        mesh
+    }
+
+    pub fn update(mesh: &mut Mesh, tile_positions: &[Vec2], tile_size: f32) {
+        let half_size = tile_size / 2.0;
+
+        let vertices: Vec<Vec3> = tile_positions
+            .iter()
+            .flat_map(|tile_position| {
+                [
+                    Vec3::new(
+                        tile_position.x - half_size,
+                        tile_position.y - half_size,
+                        0.0,
+                    ),
+                    Vec3::new(
+                        tile_position.x + half_size,
+                        tile_position.y - half_size,
+                        0.0,
+                    ),
+                    Vec3::new(
+                        tile_position.x + half_size,
+                        tile_position.y + half_size,
+                        0.0,
+                    ),
+                    Vec3::new(
+                        tile_position.x - half_size,
+                        tile_position.y + half_size,
+                        0.0,
+                    ),
+                ]
+            })
+            .collect();
+
+        let indices: Vec<u32> = (0..tile_positions.len() as u32)
+            .flat_map(|index| {
+                let offset = index * 4;
+                [
+                    offset,
+                    offset + 1,
+                    offset + 2,
+                    offset,
+                    offset + 2,
+                    offset + 3,
+                ]
+            })
+            .collect();
+
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, vertices);
+        mesh.insert_indices(Indices::U32(indices));
+    }
+}
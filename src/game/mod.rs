@@ -1,3 +1,4 @@
+pub mod abilities;
 pub mod assets;
 pub mod audio;
 pub mod camera;
@@ -15,12 +16,13 @@ use bevy_persistent::Persistent;
 use winit::window::Icon;
 
 use crate::game::{
+    abilities::{PlayerAbilities, PlayerAbilitiesPlugin},
     assets::{GameAssetsPlugin, audio::game::GameAudioAssets, levels::Level, utils::UtilAssets},
     audio::{GameAudioPlugin, GameAudioVolume},
     camera::{GameCamera, GameCameraPlugin},
     config::GameConfigPlugin,
     entities::{GameEntitiesPlugin, tile::indicator::TileIndicator, tilemap::Tilemap},
-    input::GameInputPlugin,
+    input::{GameInputPlugin, SelectedAbility},
     player::{Player, PlayerPlugin},
     speed::GameSpeed,
     ui::{GameUiPlugin, UiState},
@@ -41,6 +43,7 @@ impl Plugin for GamePlugin {
             GameWavesPlugin,
             GameInputPlugin,
             PlayerPlugin,
+            PlayerAbilitiesPlugin,
         ));
 
         app.init_state::<GameState>();
@@ -108,6 +111,8 @@ fn start_game(
     game_tilemap: Query<Entity, With<GameTilemap>>,
     selected_level: Res<Level>,
     mut player: ResMut<Player>,
+    mut player_abilities: ResMut<PlayerAbilities>,
+    mut selected_ability: ResMut<SelectedAbility>,
     mut game_waves: ResMut<GameWaves>,
     mut game_speed: ResMut<GameSpeed>,
     game_audio_assets: Res<GameAudioAssets>,
@@ -146,7 +151,13 @@ fn start_game(
         selected_level.get_player_health(),
         selected_level.get_player_money(),
     );
-    game_waves.restart(selected_level.get_waves().len().saturating_sub(1));
+    player_abilities.restart(selected_level.get_abilities());
+    selected_ability.variant = None;
+    game_waves.restart(
+        selected_level.get_waves().len().saturating_sub(1),
+        selected_level.has_overlapping_waves(),
+        selected_level.is_endless(),
+    );
     game_speed.set_default();
 
     next_ui_state.set(UiState::InGame);
@@ -1,10 +1,11 @@
 use bevy::prelude::*;
+use bevy_persistent::Persistent;
 
 use crate::game::{
     GameState,
     assets::{
         images::ui::{UiAssets, UiMiscSpriteVariant},
-        levels::LevelCompletionStars,
+        levels::{EndlessRecords, Level, LevelCompletionStars},
     },
     player::Player,
     ui::{
@@ -42,6 +43,8 @@ fn init_ui(
     mut commands: Commands,
     ui_assets: Res<UiAssets>,
     player: Res<Player>,
+    selected_level: Res<Level>,
+    endless_records: Res<Persistent<EndlessRecords>>,
     game_waves: Res<GameWaves>,
 ) {
     commands
@@ -125,7 +128,25 @@ fn init_ui(
                                 .with_size(UiTextSize::Large),
                             );
 
-                            if player.get_health().is_dead() {
+                            if player.get_health().is_dead() && game_waves.is_endless() == true {
+                                parent.spawn(
+                                    UiText::new("ui.game_over.endless_waves_survived")
+                                        .with_i18n_arg(
+                                            "current_wave",
+                                            game_waves.get_current().to_string(),
+                                        )
+                                        .with_i18n_arg(
+                                            "best_wave",
+                                            endless_records
+                                                .get_best_wave(
+                                                    selected_level.get_name(),
+                                                    selected_level.get_difficulty(),
+                                                )
+                                                .unwrap_or_default()
+                                                .to_string(),
+                                        ),
+                                );
+                            } else if player.get_health().is_dead() {
                                 parent.spawn(
                                     UiText::new("ui.game_over.waves_survived")
                                         .with_i18n_arg(
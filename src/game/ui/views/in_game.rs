@@ -2,7 +2,16 @@ use bevy::prelude::*;
 
 use crate::game::{
     GameState,
-    assets::images::ui::{UiAssets, UiMiscSpriteVariant},
+    abilities::{PlayerAbilities, PlayerAbilityActivated, PlayerAbilityVariant},
+    assets::{
+        images::ui::{UiAssets, UiMiscSpriteVariant},
+        levels::Level,
+    },
+    entities::{
+        enemy::{Enemy, boss::EnemyBoss, health::EnemyHealth},
+        tile::{movement::TileMovement, position::TilePosition},
+    },
+    input::SelectedAbility,
     player::Player,
     speed::GameSpeed,
     ui::{
@@ -16,7 +25,7 @@ use crate::game::{
         },
         i18n::I18nComponent,
     },
-    waves::GameWaves,
+    waves::{GameWaves, get_remaining_health},
 };
 
 pub struct InGameViewUiPlugin;
@@ -26,6 +35,11 @@ impl Plugin for InGameViewUiPlugin {
         app.add_systems(OnEnter(UiState::InGame), init_ui)
             .add_systems(OnExit(UiState::InGame), destroy_ui)
             .add_systems(Update, update_ui.run_if(in_state(UiState::InGame)))
+            .add_systems(Update, update_boss_bar.run_if(in_state(UiState::InGame)))
+            .add_systems(
+                Update,
+                update_next_wave_text.run_if(in_state(UiState::InGame)),
+            )
             .add_systems(
                 Update,
                 update_ui_after_player_change
@@ -35,6 +49,12 @@ impl Plugin for InGameViewUiPlugin {
                 Update,
                 update_ui_after_wave_change
                     .run_if(in_state(UiState::InGame).and(resource_changed::<GameWaves>)),
+            )
+            .add_systems(
+                Update,
+                update_ui_after_abilities_change.run_if(in_state(UiState::InGame).and(
+                    resource_changed::<PlayerAbilities>.or(resource_changed::<SelectedAbility>),
+                )),
             );
     }
 }
@@ -47,7 +67,22 @@ struct HealthTextComponent;
 #[derive(Component)]
 struct MoneyTextComponent;
 #[derive(Component)]
+struct InterestTextComponent;
+#[derive(Component)]
 struct WaveTextComponent;
+#[derive(Component)]
+struct NextWaveTextComponent;
+#[derive(Component)]
+struct BossBarComponent;
+#[derive(Component)]
+struct BossNameTextComponent;
+#[derive(Component)]
+struct BossHealthComponent;
+
+#[derive(Component)]
+struct AbilityCooldownTextComponent(PlayerAbilityVariant);
+#[derive(Component)]
+struct AbilityTargetingTextComponent;
 
 #[derive(Component)]
 struct SpeedSelector;
@@ -56,12 +91,15 @@ struct SpeedSelector;
 enum ButtonAction {
     Pause,
     NextWave,
+    UseAbility(PlayerAbilityVariant),
 }
 
 fn init_ui(
     mut commands: Commands,
     ui_assets: Res<UiAssets>,
     player: Res<Player>,
+    player_abilities: Res<PlayerAbilities>,
+    selected_level: Res<Level>,
     game_waves: Res<GameWaves>,
     game_speed: Res<GameSpeed>,
 ) {
@@ -132,6 +170,32 @@ fn init_ui(
                                             ),
                                     ));
                                 });
+
+                            if selected_level.get_interest_rate() > 0.0 {
+                                parent.spawn((
+                                    InterestTextComponent,
+                                    UiText::new(if selected_level.get_max_interest().is_some() {
+                                        "ui.in_game.interest_with_max"
+                                    } else {
+                                        "ui.in_game.interest"
+                                    })
+                                    .with_justify(Justify::Left)
+                                    .with_size(UiTextSize::Small)
+                                    .with_i18n_arg(
+                                        "rate",
+                                        (selected_level.get_interest_rate() * 100.0)
+                                            .round()
+                                            .to_string(),
+                                    )
+                                    .with_i18n_arg(
+                                        "max",
+                                        selected_level
+                                            .get_max_interest()
+                                            .unwrap_or_default()
+                                            .to_string(),
+                                    ),
+                                ));
+                            }
                         });
                 });
 
@@ -145,17 +209,123 @@ fn init_ui(
                 )
                 .with_child((
                     WaveTextComponent,
-                    UiText::new("ui.in_game.wave")
-                        .with_i18n_arg(
-                            "current",
-                            game_waves.get_current().saturating_add(1).to_string(),
-                        )
-                        .with_i18n_arg(
-                            "total",
-                            game_waves.get_total().saturating_add(1).to_string(),
-                        ),
+                    UiText::new(if game_waves.is_endless() == true {
+                        "ui.in_game.endless_wave"
+                    } else {
+                        "ui.in_game.wave"
+                    })
+                    .with_i18n_arg(
+                        "current",
+                        game_waves.get_current().saturating_add(1).to_string(),
+                    )
+                    .with_i18n_arg(
+                        "total",
+                        game_waves.get_total().saturating_add(1).to_string(),
+                    ),
                 ));
 
+            parent
+                .spawn((
+                    BossBarComponent,
+                    UiContainer::new()
+                        .with_top(Val::Px(8.0))
+                        .with_row_gap(Val::Px(4.0))
+                        .absolute()
+                        .center()
+                        .column(),
+                    Visibility::Hidden,
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        BossNameTextComponent,
+                        UiText::new("ui.in_game.boss")
+                            .with_i18n_arg("name", String::new())
+                            .with_size(UiTextSize::Small)
+                            .auto_width(),
+                    ));
+
+                    parent
+                        .spawn(
+                            UiContainer::secondary()
+                                .with_width(Val::Px(240.0))
+                                .with_height(Val::Px(20.0))
+                                .with_padding(UiRect::all(Val::Px(4.0)))
+                                .with_max_corner_scale(1.0),
+                        )
+                        .with_child((
+                            BossHealthComponent,
+                            Node {
+                                width: Val::Percent(100.0),
+                                height: Val::Percent(100.0),
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgb(1.0, 0.25, 0.25)),
+                        ));
+                });
+
+            parent
+                .spawn((
+                    AbilityTargetingTextComponent,
+                    UiContainer::new()
+                        .with_bottom(Val::Px(48.0))
+                        .absolute()
+                        .center(),
+                    Visibility::Hidden,
+                ))
+                .with_child(
+                    UiText::new("ui.in_game.ability_targeting")
+                        .with_size(UiTextSize::Small)
+                        .auto_width(),
+                );
+
+            parent
+                .spawn((
+                    Button,
+                    UiContainer::new()
+                        .with_left(Val::Px(8.0))
+                        .with_bottom(Val::Px(8.0))
+                        .with_column_gap(Val::Px(8.0))
+                        .absolute()
+                        .auto_width(),
+                ))
+                .with_children(|parent| {
+                    for player_ability in player_abilities.get_abilities().iter() {
+                        parent
+                            .spawn((
+                                ButtonAction::UseAbility(player_ability.get_variant()),
+                                UiButton::primary()
+                                    .with_disabled(player_ability.is_ready() == false)
+                                    .with_height(Val::Px(32.0))
+                                    .with_padding(UiRect::horizontal(Val::Px(16.0)))
+                                    .with_column_gap(Val::Px(4.0)),
+                            ))
+                            .with_child(
+                                UiText::new(player_ability.get_variant().to_str())
+                                    .with_size(UiTextSize::Small)
+                                    .auto_width(),
+                            )
+                            .with_child((
+                                AbilityCooldownTextComponent(player_ability.get_variant()),
+                                UiText::new("ui.in_game.ability_cooldown")
+                                    .with_i18n_arg(
+                                        "seconds",
+                                        player_ability
+                                            .get_cooldown()
+                                            .as_secs_f32()
+                                            .ceil()
+                                            .to_string(),
+                                    )
+                                    .with_size(UiTextSize::Small)
+                                    .auto_width(),
+                                if player_ability.is_ready() == true {
+                                    Visibility::Hidden
+                                } else {
+                                    Visibility::Inherited
+                                },
+                            ));
+                    }
+                });
+
             parent
                 .spawn((
                     Button,
@@ -178,11 +348,13 @@ fn init_ui(
                                 .with_column_gap(Val::Px(4.0)),
                         ))
                         .with_child(UiIcon::new(UiIconVariant::Next).with_size(UiIconSize::Small))
-                        .with_child(
+                        .with_child((
+                            NextWaveTextComponent,
                             UiText::new("ui.in_game.next_wave")
+                                .with_i18n_arg("bonus", 0.to_string())
                                 .with_size(UiTextSize::Small)
                                 .auto_width(),
-                        );
+                        ));
 
                     parent.spawn((
                         SpeedSelector,
@@ -237,6 +409,9 @@ fn update_ui(
     mut speed_selector: Query<&mut UiSelector, With<SpeedSelector>>,
     mut game_waves: ResMut<GameWaves>,
     mut game_speed: ResMut<GameSpeed>,
+    player_abilities: Res<PlayerAbilities>,
+    mut selected_ability: ResMut<SelectedAbility>,
+    mut player_ability_activated_events: MessageWriter<PlayerAbilityActivated>,
     mut next_ui_state: ResMut<NextState<UiState>>,
     mut next_game_state: ResMut<NextState<GameState>>,
 ) {
@@ -259,11 +434,33 @@ fn update_ui(
                     game_waves.next_wave();
                 }
             }
+            ButtonAction::UseAbility(variant) => {
+                if player_abilities.is_ready(*variant) == false {
+                    continue;
+                }
+                if variant.requires_target() == true {
+                    selected_ability.variant = if selected_ability.variant == Some(*variant) {
+                        None
+                    } else {
+                        Some(*variant)
+                    };
+                } else {
+                    selected_ability.variant = None;
+                    player_ability_activated_events.write(PlayerAbilityActivated {
+                        variant: *variant,
+                        tile_position: TilePosition::default(),
+                    });
+                }
+            }
         }
     }
     if keyboard_input.just_pressed(KeyCode::Escape) {
-        next_ui_state.set(UiState::Pause);
-        next_game_state.set(GameState::Pause);
+        if selected_ability.variant.is_some() {
+            selected_ability.variant = None;
+        } else {
+            next_ui_state.set(UiState::Pause);
+            next_game_state.set(GameState::Pause);
+        }
     }
     if keyboard_input.just_pressed(KeyCode::Space) {
         if game_waves.is_next_wave_allowed() == true {
@@ -292,6 +489,87 @@ fn update_ui(
     }
 }
 
+fn update_boss_bar(
+    enemies: Query<(Entity, &Enemy, &EnemyHealth, &TileMovement), With<EnemyBoss>>,
+    mut boss_bar: Query<&mut Visibility, With<BossBarComponent>>,
+    mut boss_name_text: Query<&mut I18nComponent, With<BossNameTextComponent>>,
+    mut boss_health: Query<&mut Node, With<BossHealthComponent>>,
+    mut displayed_boss_entity: Local<Option<Entity>>,
+) {
+    let boss = enemies
+        .iter()
+        .filter(|(_, _, _, enemy_movement)| enemy_movement.get_progress() > 0.0)
+        .min_by(|(_, _, _, enemy_a_movement), (_, _, _, enemy_b_movement)| {
+            enemy_a_movement
+                .get_remaining_distance()
+                .total_cmp(&enemy_b_movement.get_remaining_distance())
+        });
+
+    for mut boss_bar_visibility in boss_bar.iter_mut() {
+        let visibility = if boss.is_some() {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        if *boss_bar_visibility != visibility {
+            *boss_bar_visibility = visibility;
+        }
+    }
+
+    let Some((enemy_entity, enemy, enemy_health, _enemy_movement)) = boss else {
+        *displayed_boss_entity = None;
+        return;
+    };
+
+    if *displayed_boss_entity != Some(enemy_entity) {
+        for mut boss_name_text_i18n in boss_name_text.iter_mut() {
+            boss_name_text_i18n.change_i18n_arg("name", rust_i18n::t!(enemy.to_str()).to_string());
+        }
+        *displayed_boss_entity = Some(enemy_entity);
+    }
+    for mut boss_health_node in boss_health.iter_mut() {
+        let width = Val::Percent(enemy_health.get_percentage() * 100.0);
+        if boss_health_node.width != width {
+            boss_health_node.width = width;
+        }
+    }
+}
+
+fn update_next_wave_text(
+    enemies: Query<(&EnemyHealth, &TileMovement), With<Enemy>>,
+    mut next_wave_text: Query<&mut I18nComponent, With<NextWaveTextComponent>>,
+    added_next_wave_text: Query<(), Added<NextWaveTextComponent>>,
+    selected_level: Res<Level>,
+    game_waves: Res<GameWaves>,
+    mut displayed_bonus: Local<Option<u32>>,
+) {
+    if added_next_wave_text.is_empty() == false {
+        *displayed_bonus = None;
+    }
+
+    let bonus = if selected_level.has_early_call_bonus() == true
+        && game_waves.is_overlapping_allowed() == true
+    {
+        Some(selected_level.get_early_call_bonus(get_remaining_health(&enemies)))
+    } else {
+        None
+    };
+
+    if *displayed_bonus == bonus {
+        return;
+    }
+
+    for mut next_wave_text_i18n in next_wave_text.iter_mut() {
+        if let Some(bonus) = bonus {
+            next_wave_text_i18n.change_i18n_key("ui.in_game.call_wave_early".to_string());
+            next_wave_text_i18n.change_i18n_arg("bonus", bonus.to_string());
+        } else {
+            next_wave_text_i18n.change_i18n_key("ui.in_game.next_wave".to_string());
+        }
+    }
+    *displayed_bonus = bonus;
+}
+
 fn update_ui_after_player_change(
     player: Res<Player>,
     mut health_text: Query<
@@ -327,8 +605,72 @@ fn update_ui_after_wave_change(
         )
     }
     for (mut ui_button, button_action) in next_wave_button.iter_mut() {
-        ui_button.set_next_disabled_state(
-            *button_action == ButtonAction::NextWave && game_waves.is_next_wave_allowed() == false,
+        if *button_action != ButtonAction::NextWave {
+            continue;
+        }
+        ui_button.set_next_disabled_state(game_waves.is_next_wave_allowed() == false);
+    }
+}
+
+fn update_ui_after_abilities_change(
+    player_abilities: Res<PlayerAbilities>,
+    selected_ability: Res<SelectedAbility>,
+    mut ability_buttons: Query<(&mut UiButton, &ButtonAction)>,
+    mut ability_cooldown_text: Query<
+        (
+            &mut I18nComponent,
+            &mut Visibility,
+            &AbilityCooldownTextComponent,
+        ),
+        Without<AbilityTargetingTextComponent>,
+    >,
+    mut ability_targeting_text: Query<
+        &mut Visibility,
+        (
+            With<AbilityTargetingTextComponent>,
+            Without<AbilityCooldownTextComponent>,
+        ),
+    >,
+) {
+    for (mut ui_button, button_action) in ability_buttons.iter_mut() {
+        if let ButtonAction::UseAbility(variant) = button_action {
+            ui_button.set_next_disabled_state(player_abilities.is_ready(*variant) == false);
+        }
+    }
+    for (
+        mut ability_cooldown_text_i18n,
+        mut ability_cooldown_text_visibility,
+        ability_cooldown_text,
+    ) in ability_cooldown_text.iter_mut()
+    {
+        let Some(player_ability) = player_abilities.get_ability(ability_cooldown_text.0) else {
+            continue;
+        };
+        let visibility = if player_ability.is_ready() == true {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        };
+        if *ability_cooldown_text_visibility != visibility {
+            *ability_cooldown_text_visibility = visibility;
+        }
+        ability_cooldown_text_i18n.change_i18n_arg(
+            "seconds",
+            player_ability
+                .get_cooldown()
+                .as_secs_f32()
+                .ceil()
+                .to_string(),
         );
     }
+    for mut ability_targeting_text_visibility in ability_targeting_text.iter_mut() {
+        let visibility = if selected_ability.variant.is_some() {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        if *ability_targeting_text_visibility != visibility {
+            *ability_targeting_text_visibility = visibility;
+        }
+    }
 }
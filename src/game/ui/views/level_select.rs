@@ -8,15 +8,20 @@ use crate::game::{
     assets::{
         audio::ui::UiAudioAssets,
         images::ui::{UiAssets, UiButtonSpriteVariant, UiMiscSpriteVariant},
-        levels::{CompletedLevels, Level, LevelCompletionStars, LevelsAssets},
+        levels::{
+            CompletedLevels, Level, LevelCompletionStars, LevelDifficulty,
+            LevelDifficultyModifiers, LevelsAssets,
+        },
     },
     audio::{GameAudio, GameAudioVolume},
+    config::GameConfig,
     ui::{
         UiState,
         components::{
             button::{UiButton, UiButtonInteraction, UiButtonVariant},
             container::UiContainer,
             icon::{UiIcon, UiIconVariant},
+            selector::{UiSelector, UiSelectorItem, UiSelectorItemValue, UiSelectorSize},
             text::{UiText, UiTextSize},
         },
     },
@@ -32,7 +37,13 @@ impl Plugin for LevelSelectViewUiPlugin {
             .add_systems(OnExit(UiState::LevelSelect), destroy_ui)
             .add_systems(
                 Update,
-                (update_ui, uploaded_level_update).run_if(in_state(UiState::LevelSelect)),
+                (
+                    update_ui,
+                    update_ui_after_difficulty_change
+                        .run_if(resource_changed::<Persistent<GameConfig>>),
+                    uploaded_level_update,
+                )
+                    .run_if(in_state(UiState::LevelSelect)),
             );
     }
 }
@@ -47,6 +58,72 @@ enum ButtonAction {
     UploadLevel,
 }
 
+#[derive(Component)]
+struct DifficultySelector;
+
+#[derive(Component)]
+struct CustomDifficultyContainer;
+
+#[derive(Component, Clone, Copy)]
+enum CustomDifficultySelector {
+    PlayerHealth,
+    PlayerMoney,
+    EnemyHealth,
+    WaveReward,
+    SellPrice,
+}
+
+impl CustomDifficultySelector {
+    fn get_all() -> [CustomDifficultySelector; 5] {
+        [
+            CustomDifficultySelector::PlayerHealth,
+            CustomDifficultySelector::PlayerMoney,
+            CustomDifficultySelector::EnemyHealth,
+            CustomDifficultySelector::WaveReward,
+            CustomDifficultySelector::SellPrice,
+        ]
+    }
+    fn to_str(&self) -> &'static str {
+        match self {
+            CustomDifficultySelector::PlayerHealth => {
+                "ui.level_select.custom_difficulty.player_health"
+            }
+            CustomDifficultySelector::PlayerMoney => {
+                "ui.level_select.custom_difficulty.player_money"
+            }
+            CustomDifficultySelector::EnemyHealth => {
+                "ui.level_select.custom_difficulty.enemy_health"
+            }
+            CustomDifficultySelector::WaveReward => "ui.level_select.custom_difficulty.wave_reward",
+            CustomDifficultySelector::SellPrice => "ui.level_select.custom_difficulty.sell_price",
+        }
+    }
+    fn get_value(&self, modifiers: &LevelDifficultyModifiers) -> f32 {
+        match self {
+            CustomDifficultySelector::PlayerHealth => modifiers.get_player_health(),
+            CustomDifficultySelector::PlayerMoney => modifiers.get_player_money(),
+            CustomDifficultySelector::EnemyHealth => modifiers.get_enemy_health(),
+            CustomDifficultySelector::WaveReward => modifiers.get_wave_reward(),
+            CustomDifficultySelector::SellPrice => modifiers.get_sell_price(),
+        }
+    }
+    fn set_value(&self, modifiers: &mut LevelDifficultyModifiers, value: f32) {
+        match self {
+            CustomDifficultySelector::PlayerHealth => modifiers.set_player_health(value),
+            CustomDifficultySelector::PlayerMoney => modifiers.set_player_money(value),
+            CustomDifficultySelector::EnemyHealth => modifiers.set_enemy_health(value),
+            CustomDifficultySelector::WaveReward => modifiers.set_wave_reward(value),
+            CustomDifficultySelector::SellPrice => modifiers.set_sell_price(value),
+        }
+    }
+}
+
+#[derive(Component)]
+struct LevelStarComponent {
+    level_index: usize,
+    star_index: usize,
+}
+
 #[derive(Resource)]
 struct UploadedLevel {
     task: Option<Task<Option<Level>>>,
@@ -66,6 +143,7 @@ fn init_ui(
     levels_assets_loader: Res<Assets<Level>>,
     mut images: ResMut<Assets<Image>>,
     completed_levels: Res<Persistent<CompletedLevels>>,
+    game_config: Res<Persistent<GameConfig>>,
 ) {
     commands
         .spawn((
@@ -135,8 +213,10 @@ fn init_ui(
                             {
                                 let level = levels_assets_loader.get(level_handle).unwrap();
 
-                                let level_completion =
-                                    completed_levels.get_completion(&level.get_name());
+                                let level_completion = completed_levels.get_completion(
+                                    &level.get_name(),
+                                    game_config.get_difficulty(),
+                                );
                                 let level_stars = if level_completion.is_some() {
                                     level_completion.unwrap().get_stars()
                                 } else {
@@ -153,7 +233,10 @@ fn init_ui(
                                                     .with_variant(if level.get_error().is_some() {
                                                         UiButtonVariant::Danger
                                                     } else {
-                                                        if level_completion.is_some() {
+                                                        if completed_levels
+                                                            .is_completed(&level.get_name())
+                                                            == true
+                                                        {
                                                             UiButtonVariant::Success
                                                         } else {
                                                             UiButtonVariant::Secondary
@@ -181,6 +264,10 @@ fn init_ui(
                                                     .with_children(|parent| {
                                                         for star_index in 1..=3 {
                                                             parent.spawn((
+                                                                LevelStarComponent {
+                                                                    level_index,
+                                                                    star_index,
+                                                                },
                                                                 UiContainer::new()
                                                                     .with_bottom(
                                                                         if star_index == 2 {
@@ -234,6 +321,89 @@ fn init_ui(
                             }
                         });
 
+                    parent
+                        .spawn(
+                            UiContainer::new()
+                                .with_width(Val::Percent(100.0))
+                                .with_row_gap(Val::Px(8.0))
+                                .column(),
+                        )
+                        .with_children(|parent| {
+                            parent.spawn(
+                                UiText::new("ui.level_select.difficulty")
+                                    .with_size(UiTextSize::Small),
+                            );
+
+                            parent.spawn((
+                                DifficultySelector,
+                                UiSelector::new()
+                                    .with_size(UiSelectorSize::Small)
+                                    .with_options(
+                                        LevelDifficulty::get_all()
+                                            .iter()
+                                            .map(|difficulty| {
+                                                UiSelectorItem::new(difficulty.to_str()).with_value(
+                                                    UiSelectorItemValue::Number(
+                                                        difficulty.as_index() as f32,
+                                                    ),
+                                                )
+                                            })
+                                            .collect(),
+                                    )
+                                    .with_default_index(game_config.get_difficulty().as_index())
+                                    .cycle(),
+                            ));
+                        });
+
+                    parent
+                        .spawn((
+                            CustomDifficultyContainer,
+                            UiContainer::new()
+                                .with_display(
+                                    if game_config.get_difficulty() == LevelDifficulty::Custom {
+                                        Display::Flex
+                                    } else {
+                                        Display::None
+                                    },
+                                )
+                                .with_width(Val::Percent(100.0))
+                                .with_row_gap(Val::Px(8.0))
+                                .column(),
+                        ))
+                        .with_children(|parent| {
+                            for custom_difficulty_selector in CustomDifficultySelector::get_all() {
+                                let value = custom_difficulty_selector
+                                    .get_value(game_config.get_custom_difficulty_modifiers());
+
+                                parent.spawn(
+                                    UiText::new(custom_difficulty_selector.to_str())
+                                        .with_size(UiTextSize::Small),
+                                );
+
+                                parent.spawn((
+                                    custom_difficulty_selector,
+                                    UiSelector::new()
+                                        .with_size(UiSelectorSize::Small)
+                                        .with_options(
+                                            (1..=12)
+                                                .map(|index| {
+                                                    UiSelectorItem::new(&format!(
+                                                        "{}%",
+                                                        index * 25
+                                                    ))
+                                                    .with_value(UiSelectorItemValue::Number(
+                                                        index as f32 / 4.0,
+                                                    ))
+                                                })
+                                                .collect(),
+                                        )
+                                        .with_default_index(
+                                            ((value * 4.0).round() as usize).clamp(1, 12) - 1,
+                                        ),
+                                ));
+                            }
+                        });
+
                     #[cfg(not(target_os = "android"))]
                     parent
                         .spawn((ButtonAction::UploadLevel, UiButton::primary()))
@@ -269,13 +439,43 @@ fn update_ui(
         (Changed<UiButtonInteraction>, With<UiButton>),
     >,
     keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut difficulty_selectors: ParamSet<(
+        Query<&mut UiSelector, With<DifficultySelector>>,
+        Query<(&mut UiSelector, &CustomDifficultySelector)>,
+    )>,
     levels_assets: Res<LevelsAssets>,
     levels_assets_loader: Res<Assets<Level>>,
+    mut game_config: ResMut<Persistent<GameConfig>>,
     mut uploaded_level: ResMut<UploadedLevel>,
     mut selected_level: ResMut<Level>,
     mut next_ui_state: ResMut<NextState<UiState>>,
     mut next_game_state: ResMut<NextState<GameState>>,
 ) {
+    for mut difficulty_selector in difficulty_selectors.p0().iter_mut() {
+        if let Some(changed_item) = difficulty_selector.get_changed_item() {
+            game_config
+                .update(|game_config| {
+                    game_config.set_difficulty(LevelDifficulty::from_index(
+                        changed_item.value.as_f32() as usize,
+                    ));
+                })
+                .unwrap();
+        }
+    }
+    for (mut custom_difficulty_selector, custom_difficulty_modifier) in
+        difficulty_selectors.p1().iter_mut()
+    {
+        if let Some(changed_item) = custom_difficulty_selector.get_changed_item() {
+            game_config
+                .update(|game_config| {
+                    custom_difficulty_modifier.set_value(
+                        game_config.get_custom_difficulty_modifiers_mut(),
+                        changed_item.value.as_f32(),
+                    );
+                })
+                .unwrap();
+        }
+    }
     for (ui_button_interaction, button_action) in interaction_query.iter() {
         if *ui_button_interaction != UiButtonInteraction::Clicked {
             continue;
@@ -288,7 +488,10 @@ fn update_ui(
                 if let Some(level) = levels_assets_loader.get(&levels_assets.compain[*level_index])
                 {
                     if level.get_error().is_none() {
-                        *selected_level = level.clone();
+                        *selected_level = level.clone().with_difficulty(
+                            game_config.get_difficulty(),
+                            game_config.get_difficulty_modifiers(),
+                        );
                         next_game_state.set(GameState::Start);
                     }
                 }
@@ -322,11 +525,45 @@ fn update_ui(
     }
 }
 
+fn update_ui_after_difficulty_change(
+    mut custom_difficulty_container: Single<&mut Node, With<CustomDifficultyContainer>>,
+    mut level_stars: Query<(&LevelStarComponent, &mut ImageNode)>,
+    levels_assets: Res<LevelsAssets>,
+    levels_assets_loader: Res<Assets<Level>>,
+    completed_levels: Res<Persistent<CompletedLevels>>,
+    game_config: Res<Persistent<GameConfig>>,
+) {
+    custom_difficulty_container.display = if game_config.get_difficulty() == LevelDifficulty::Custom
+    {
+        Display::Flex
+    } else {
+        Display::None
+    };
+
+    for (level_star, mut level_star_image) in level_stars.iter_mut() {
+        let Some(level) = levels_assets_loader.get(&levels_assets.compain[level_star.level_index])
+        else {
+            continue;
+        };
+        let level_stars = completed_levels
+            .get_completion(&level.get_name(), game_config.get_difficulty())
+            .map(|level_completion| level_completion.get_stars().as_index())
+            .unwrap_or(LevelCompletionStars::Zero.as_index());
+
+        level_star_image.color = if level_star.star_index <= level_stars {
+            Color::srgb(1.0, 1.0, 0.0)
+        } else {
+            Color::WHITE
+        };
+    }
+}
+
 fn uploaded_level_update(
     mut commands: Commands,
     game_audio: Single<Entity, With<GameAudio>>,
     game_audio_volume: Res<Persistent<GameAudioVolume>>,
     ui_audio_assets: Res<UiAudioAssets>,
+    game_config: Res<Persistent<GameConfig>>,
     mut uploaded_level: ResMut<UploadedLevel>,
     mut selected_level: ResMut<Level>,
     mut next_game_state: ResMut<NextState<GameState>>,
@@ -346,7 +583,10 @@ fn uploaded_level_update(
                             .show();
                     }
                 } else {
-                    *selected_level = level.clone();
+                    *selected_level = level.with_difficulty(
+                        game_config.get_difficulty(),
+                        game_config.get_difficulty_modifiers(),
+                    );
                     next_game_state.set(GameState::Start);
 
                     commands.entity(game_audio.entity()).with_child((
@@ -4,7 +4,7 @@ use bevy_persistent::Persistent;
 use crate::game::{
     assets::{
         images::ui::{UiAssets, UiButtonSpriteVariant, UiMiscSpriteVariant},
-        levels::CompletedLevels,
+        levels::{CompletedLevels, EndlessRecords},
     },
     audio::GameAudioVolume,
     ui::{
@@ -53,6 +53,7 @@ fn init_ui(
     ui_assets: Res<UiAssets>,
     i18n: Res<Persistent<I18n>>,
     completed_levels: Res<Persistent<CompletedLevels>>,
+    endless_records: Res<Persistent<EndlessRecords>>,
     game_audio_volume: Res<Persistent<GameAudioVolume>>,
 ) {
     commands
@@ -207,7 +208,9 @@ fn init_ui(
                     parent
                         .spawn((
                             ButtonAction::ResetProgress,
-                            UiButton::danger().with_disabled(completed_levels.is_empty()),
+                            UiButton::danger().with_disabled(
+                                completed_levels.is_empty() && endless_records.is_empty(),
+                            ),
                         ))
                         .with_child(UiIcon::new(UiIconVariant::Delete))
                         .with_child(UiText::new("ui.settings.reset_progress").auto_width());
@@ -249,6 +252,7 @@ fn update_ui(
     mut i18n: ResMut<Persistent<I18n>>,
     mut game_audio_volume: ResMut<Persistent<GameAudioVolume>>,
     mut completed_levels: ResMut<Persistent<CompletedLevels>>,
+    mut endless_records: ResMut<Persistent<EndlessRecords>>,
     mut next_ui_state: ResMut<NextState<UiState>>,
 ) {
     for mut locale_selector in settings_selectors.p0().iter_mut() {
@@ -286,10 +290,11 @@ fn update_ui(
                 next_ui_state.set(UiState::Menu);
             }
             ButtonAction::ResetProgress => {
-                if completed_levels.is_empty() {
+                if completed_levels.is_empty() && endless_records.is_empty() {
                     continue;
                 }
                 completed_levels.update(|levels| levels.reset()).unwrap();
+                endless_records.update(|records| records.reset()).unwrap();
                 next_ui_state.set(UiState::Menu);
             }
         }
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use bevy::prelude::*;
 
 use crate::game::{
@@ -8,9 +10,10 @@ use crate::game::{
             entity::EntityAssets,
             ui::{UiAssets, UiButtonSpriteVariant, UiMiscSpriteVariant},
         },
+        levels::Level,
     },
     entities::{
-        soldier::{Soldier, SoldierTargetPriority},
+        soldier::{Soldier, SoldierTargetMode, SoldierTargetPriority, config::SoldierConfig},
         tile::{position::TilePosition, sprite::TileSprite},
     },
     input::{SelectedSoldier, SelectedTile},
@@ -51,15 +54,23 @@ enum SoldierInfoComponent {
     FireRadius,
     BlastRadius,
     FireRate,
+    Charges,
+    TargetDomains,
+    AuraDamage,
+    AuraFireRate,
+    AuraFireRadius,
 }
 
 #[derive(Component)]
 struct SoldierTargetPrioritySelector;
 
+#[derive(Component)]
+struct SoldierTargetModeSelector;
+
 #[derive(Component, PartialEq)]
 enum ButtonAction {
     Close,
-    UpgradeSoldier,
+    UpgradeSoldier(usize),
     SellSoldier,
 }
 
@@ -72,6 +83,7 @@ fn init_ui(
     game_waves: Res<GameWaves>,
     soldiers: Query<(&Soldier, &TilePosition)>,
     selected_soldier: Res<SelectedSoldier>,
+    selected_level: Res<Level>,
 ) {
     commands
         .spawn((
@@ -154,15 +166,19 @@ fn init_ui(
                         .absolute(),
                 )
                 .with_child(
-                    UiText::new("ui.in_game.wave")
-                        .with_i18n_arg(
-                            "current",
-                            game_waves.get_current().saturating_add(1).to_string(),
-                        )
-                        .with_i18n_arg(
-                            "total",
-                            game_waves.get_total().saturating_add(1).to_string(),
-                        ),
+                    UiText::new(if game_waves.is_endless() == true {
+                        "ui.in_game.endless_wave"
+                    } else {
+                        "ui.in_game.wave"
+                    })
+                    .with_i18n_arg(
+                        "current",
+                        game_waves.get_current().saturating_add(1).to_string(),
+                    )
+                    .with_i18n_arg(
+                        "total",
+                        game_waves.get_total().saturating_add(1).to_string(),
+                    ),
                 );
 
             parent
@@ -258,7 +274,7 @@ fn init_ui(
                                                     "level",
                                                     soldier
                                                         .get_variant()
-                                                        .get_level()
+                                                        .get_tier()
                                                         .saturating_add(1)
                                                         .to_string(),
                                                 )
@@ -266,7 +282,7 @@ fn init_ui(
                                                     "max_level",
                                                     soldier
                                                         .get_variant()
-                                                        .get_max_level()
+                                                        .get_max_tier()
                                                         .saturating_add(1)
                                                         .to_string(),
                                                 )
@@ -274,131 +290,340 @@ fn init_ui(
                                                 .with_justify(Justify::Left),
                                         ));
 
-                                        parent.spawn((
-                                            SoldierInfoComponent::Damage,
-                                            UiText::new("soldier.info.damage")
-                                                .with_i18n_arg(
-                                                    "damage",
-                                                    soldier.get_damage().to_string(),
-                                                )
-                                                .with_size(UiTextSize::Small)
-                                                .with_justify(Justify::Left),
-                                        ));
+                                        if let Some(aura) =
+                                            soldier.get_config().get_attack().get_aura()
+                                        {
+                                            parent.spawn((
+                                                SoldierInfoComponent::FireRadius,
+                                                UiText::new("soldier.info.aura_radius")
+                                                    .with_i18n_arg(
+                                                        "fire_radius",
+                                                        soldier.get_fire_radius().to_string(),
+                                                    )
+                                                    .with_size(UiTextSize::Small)
+                                                    .with_justify(Justify::Left),
+                                            ));
 
-                                        parent.spawn((
-                                            SoldierInfoComponent::FireRadius,
-                                            UiText::new("soldier.info.fire_radius")
-                                                .with_i18n_arg(
-                                                    "fire_radius",
-                                                    soldier.get_fire_radius().to_string(),
-                                                )
-                                                .with_size(UiTextSize::Small)
-                                                .with_justify(Justify::Left),
-                                        ));
+                                            for (soldier_info_component, key, bonus) in [
+                                                (
+                                                    SoldierInfoComponent::AuraDamage,
+                                                    "soldier.info.aura_damage",
+                                                    aura.get_damage(),
+                                                ),
+                                                (
+                                                    SoldierInfoComponent::AuraFireRate,
+                                                    "soldier.info.aura_fire_rate",
+                                                    aura.get_fire_rate(),
+                                                ),
+                                                (
+                                                    SoldierInfoComponent::AuraFireRadius,
+                                                    "soldier.info.aura_fire_radius",
+                                                    aura.get_fire_radius(),
+                                                ),
+                                            ] {
+                                                parent.spawn((
+                                                    soldier_info_component,
+                                                    UiText::new(key)
+                                                        .with_i18n_arg(
+                                                            "bonus",
+                                                            format_aura_bonus(bonus),
+                                                        )
+                                                        .with_size(UiTextSize::Small)
+                                                        .with_justify(Justify::Left),
+                                                ));
+                                            }
+                                        } else {
+                                            let remaining_charges = soldier.get_remaining_charges();
 
-                                        if let Some(blast_radius) = soldier
-                                            .get_config()
-                                            .get_projectile_variant()
-                                            .get_blast_radius()
-                                        {
                                             parent.spawn((
-                                                SoldierInfoComponent::BlastRadius,
-                                                UiText::new("soldier.info.blast_radius")
+                                                SoldierInfoComponent::Damage,
+                                                UiText::new("soldier.info.damage")
                                                     .with_i18n_arg(
-                                                        "blast_radius",
-                                                        blast_radius.to_string(),
+                                                        "damage",
+                                                        format_buffed_value(
+                                                            soldier.get_config().get_damage()
+                                                                as f32,
+                                                            soldier.get_damage() as f32,
+                                                        ),
                                                     )
                                                     .with_size(UiTextSize::Small)
                                                     .with_justify(Justify::Left),
                                             ));
-                                        }
 
-                                        parent.spawn((
-                                            SoldierInfoComponent::FireRate,
-                                            UiText::new("soldier.info.fire_rate")
-                                                .with_i18n_arg(
-                                                    "fire_rate",
-                                                    ((1.0 / soldier.get_fire_rate().as_secs_f32()
-                                                        * 100.0)
-                                                        .round()
-                                                        / 100.0)
-                                                        .to_string(),
-                                                )
-                                                .with_size(UiTextSize::Small)
-                                                .with_justify(Justify::Left),
-                                        ));
+                                            if remaining_charges.is_none() {
+                                                parent.spawn((
+                                                    SoldierInfoComponent::FireRadius,
+                                                    UiText::new("soldier.info.fire_radius")
+                                                        .with_i18n_arg(
+                                                            "fire_radius",
+                                                            format_buffed_value(
+                                                                soldier
+                                                                    .get_config()
+                                                                    .get_fire_radius(),
+                                                                soldier.get_fire_radius(),
+                                                            ),
+                                                        )
+                                                        .with_size(UiTextSize::Small)
+                                                        .with_justify(Justify::Left),
+                                                ));
+                                            }
+
+                                            if let Some(blast_radius) =
+                                                soldier.get_config().get_blast_radius()
+                                            {
+                                                parent.spawn((
+                                                    SoldierInfoComponent::BlastRadius,
+                                                    UiText::new("soldier.info.blast_radius")
+                                                        .with_i18n_arg(
+                                                            "blast_radius",
+                                                            blast_radius.to_string(),
+                                                        )
+                                                        .with_size(UiTextSize::Small)
+                                                        .with_justify(Justify::Left),
+                                                ));
+                                            }
+
+                                            if let Some(remaining_charges) = remaining_charges {
+                                                parent.spawn((
+                                                    SoldierInfoComponent::Charges,
+                                                    UiText::new("soldier.info.charges")
+                                                        .with_i18n_arg(
+                                                            "charges",
+                                                            remaining_charges.to_string(),
+                                                        )
+                                                        .with_size(UiTextSize::Small)
+                                                        .with_justify(Justify::Left),
+                                                ));
+                                            } else {
+                                                parent.spawn((
+                                                    SoldierInfoComponent::FireRate,
+                                                    UiText::new("soldier.info.fire_rate")
+                                                        .with_i18n_arg(
+                                                            "fire_rate",
+                                                            format_buffed_value(
+                                                                get_shots_per_second(
+                                                                    soldier
+                                                                        .get_config()
+                                                                        .get_fire_rate(),
+                                                                ),
+                                                                get_shots_per_second(
+                                                                    soldier.get_fire_rate(),
+                                                                ),
+                                                            ),
+                                                        )
+                                                        .with_size(UiTextSize::Small)
+                                                        .with_justify(Justify::Left),
+                                                ));
+                                            }
+
+                                            parent.spawn((
+                                                SoldierInfoComponent::TargetDomains,
+                                                UiText::new("soldier.info.target_domains")
+                                                    .with_i18n_arg(
+                                                        "domains",
+                                                        soldier
+                                                            .get_config()
+                                                            .get_target_domains()
+                                                            .iter()
+                                                            .map(|domain| {
+                                                                rust_i18n::t!(domain.to_str())
+                                                                    .to_string()
+                                                            })
+                                                            .collect::<Vec<_>>()
+                                                            .join(", "),
+                                                    )
+                                                    .with_size(UiTextSize::Small)
+                                                    .with_justify(Justify::Left),
+                                            ));
+
+                                            if soldier
+                                                .get_config()
+                                                .get_projectile_variant()
+                                                .is_some()
+                                            {
+                                                let next_rank_config =
+                                                    soldier.get_next_rank_config();
+
+                                                for (key, arg, value) in [
+                                                    (
+                                                        "soldier.info.rank",
+                                                        "rank",
+                                                        format!(
+                                                            "{}/{}",
+                                                            soldier.get_rank(),
+                                                            soldier.get_max_rank()
+                                                        ),
+                                                    ),
+                                                    (
+                                                        "soldier.info.kills",
+                                                        "kills",
+                                                        format_rank_progress(
+                                                            soldier.get_kills(),
+                                                            next_rank_config
+                                                                .map(|rank| rank.get_kills()),
+                                                        ),
+                                                    ),
+                                                    (
+                                                        "soldier.info.damage_dealt",
+                                                        "damage_dealt",
+                                                        format_rank_progress(
+                                                            soldier.get_damage_dealt(),
+                                                            next_rank_config.map(|rank| {
+                                                                rank.get_damage_dealt()
+                                                            }),
+                                                        ),
+                                                    ),
+                                                ] {
+                                                    parent.spawn(
+                                                        UiText::new(key)
+                                                            .with_i18n_arg(arg, value)
+                                                            .with_size(UiTextSize::Small)
+                                                            .with_justify(Justify::Left),
+                                                    );
+                                                }
+                                            }
+                                        }
                                     });
                             });
 
                         parent
                             .spawn(UiContainer::new().with_row_gap(Val::Px(8.0)).column())
                             .with_children(|parent| {
-                                parent
-                                    .spawn(UiContainer::new().column().center())
-                                    .with_children(|parent| {
-                                        parent.spawn(
-                                            UiText::new("ui.soldier_info.target_priority")
-                                                .with_size(UiTextSize::Small),
-                                        );
+                                if soldier.get_config().get_attack().is_passive() == false {
+                                    let target_priorities = SoldierTargetPriority::get_all()
+                                        .into_iter()
+                                        .filter(|priority| {
+                                            priority.get_domain().is_none_or(|domain| {
+                                                soldier.get_config().can_target(domain)
+                                            })
+                                        })
+                                        .collect::<Vec<_>>();
 
-                                        parent.spawn((
-                                            SoldierTargetPrioritySelector,
-                                            UiSelector::new()
-                                                .with_size(UiSelectorSize::Small)
-                                                .with_options(
-                                                    [
-                                                        SoldierTargetPriority::First,
-                                                        SoldierTargetPriority::Last,
-                                                        SoldierTargetPriority::Strongest,
-                                                        SoldierTargetPriority::Weakest,
-                                                        SoldierTargetPriority::Nearest,
-                                                    ]
-                                                    .iter()
-                                                    .map(|priority| {
-                                                        UiSelectorItem::new(priority.to_str())
+                                    parent
+                                        .spawn(UiContainer::new().column().center())
+                                        .with_children(|parent| {
+                                            parent.spawn(
+                                                UiText::new("ui.soldier_info.target_priority")
+                                                    .with_size(UiTextSize::Small),
+                                            );
+
+                                            parent.spawn((
+                                                SoldierTargetPrioritySelector,
+                                                UiSelector::new()
+                                                    .with_size(UiSelectorSize::Small)
+                                                    .with_options(
+                                                        target_priorities
+                                                            .iter()
+                                                            .map(|priority| {
+                                                                UiSelectorItem::new(
+                                                                    priority.to_str(),
+                                                                )
+                                                                .with_value(
+                                                                    UiSelectorItemValue::Number(
+                                                                        priority.as_index() as f32,
+                                                                    ),
+                                                                )
+                                                            })
+                                                            .collect(),
+                                                    )
+                                                    .with_default_index(
+                                                        target_priorities
+                                                            .iter()
+                                                            .position(|priority| {
+                                                                priority.as_index()
+                                                                    == soldier
+                                                                        .get_target_priority()
+                                                                        .as_index()
+                                                            })
+                                                            .unwrap_or_default(),
+                                                    )
+                                                    .cycle(),
+                                            ));
+
+                                            parent.spawn((
+                                                SoldierTargetModeSelector,
+                                                UiSelector::new()
+                                                    .with_size(UiSelectorSize::Small)
+                                                    .with_options(
+                                                        [
+                                                            SoldierTargetMode::Switch,
+                                                            SoldierTargetMode::Sticky,
+                                                        ]
+                                                        .iter()
+                                                        .map(|target_mode| {
+                                                            UiSelectorItem::new(
+                                                                target_mode.to_str(),
+                                                            )
                                                             .with_value(
                                                                 UiSelectorItemValue::Number(
-                                                                    priority.as_index() as f32,
+                                                                    target_mode.as_index() as f32,
                                                                 ),
                                                             )
-                                                    })
-                                                    .collect(),
-                                                )
-                                                .with_default_index(
-                                                    soldier.get_target_priority().as_index(),
-                                                )
-                                                .cycle(),
-                                        ));
-                                    });
+                                                        })
+                                                        .collect(),
+                                                    )
+                                                    .with_default_index(
+                                                        soldier.get_target_mode().as_index(),
+                                                    )
+                                                    .cycle(),
+                                            ));
+                                        });
+                                }
 
-                                if soldier.is_next_level_allowed() == true {
+                                let upgrades = if soldier.is_temporary() == true {
+                                    Vec::new()
+                                } else {
+                                    soldier.get_upgrades()
+                                };
+                                for upgrade in upgrades.iter() {
                                     parent
                                         .spawn((
-                                            ButtonAction::UpgradeSoldier,
+                                            ButtonAction::UpgradeSoldier(upgrade.get_level()),
                                             UiButton::success()
                                                 .with_disabled(
                                                     player.get_money().get_current()
-                                                        < soldier
-                                                            .get_variant()
-                                                            .get_next_level_config()
-                                                            .get_price(),
+                                                        < upgrade.get_config().get_price(),
                                                 )
                                                 .with_click_audio(
                                                     ui_audio_assets.soldier_upgrade.clone(),
                                                 )
-                                                .with_padding(UiRect::all(Val::Px(8.0))),
+                                                .with_padding(UiRect::all(Val::Px(8.0)))
+                                                .with_column_gap(Val::Px(8.0)),
                                         ))
-                                        .with_child(
-                                            UiText::new("ui.soldier_info.upgrade_soldier")
-                                                .with_i18n_arg(
-                                                    "price",
-                                                    soldier
-                                                        .get_variant()
-                                                        .get_next_level_config()
-                                                        .get_price()
-                                                        .to_string(),
-                                                ),
-                                        );
+                                        .with_children(|parent| {
+                                            if upgrades.len() > 1 {
+                                                parent.spawn((
+                                                    UiContainer::new()
+                                                        .with_width(Val::Px(24.0))
+                                                        .with_height(Val::Px(24.0)),
+                                                    ImageNode {
+                                                        image: entity_assets.tilemap.clone(),
+                                                        texture_atlas: Some(TextureAtlas {
+                                                            index: TileSprite::new(
+                                                                (*upgrade).into(),
+                                                            )
+                                                            .get_variant()
+                                                            .as_index(),
+                                                            layout: entity_assets
+                                                                .tilemap_layout
+                                                                .clone(),
+                                                        }),
+                                                        ..default()
+                                                    },
+                                                ));
+                                            }
+                                            parent.spawn(
+                                                UiText::new("ui.soldier_info.upgrade_soldier")
+                                                    .with_i18n_arg(
+                                                        "price",
+                                                        upgrade
+                                                            .get_config()
+                                                            .get_price()
+                                                            .to_string(),
+                                                    )
+                                                    .auto_width(),
+                                            );
+                                        });
                                 }
 
                                 parent
@@ -411,7 +636,9 @@ fn init_ui(
                                     .with_child(
                                         UiText::new("ui.soldier_info.sell_soldier").with_i18n_arg(
                                             "sell_price",
-                                            soldier.get_config().get_sell_price().to_string(),
+                                            selected_level
+                                                .get_sell_price(soldier.get_sell_price())
+                                                .to_string(),
                                         ),
                                     );
                             });
@@ -437,9 +664,17 @@ fn update_ui(
         &mut UiSelector,
         With<SoldierTargetPrioritySelector>,
     >,
+    mut soldier_target_mode_selector: Query<
+        &mut UiSelector,
+        (
+            With<SoldierTargetModeSelector>,
+            Without<SoldierTargetPrioritySelector>,
+        ),
+    >,
     mut soldiers: Query<(Entity, &mut Soldier, &TilePosition)>,
     mut player: ResMut<Player>,
     selected_soldier: Res<SelectedSoldier>,
+    selected_level: Res<Level>,
     mut selected_tile: ResMut<SelectedTile>,
     mut next_ui_state: ResMut<NextState<UiState>>,
     mut next_game_state: ResMut<NextState<GameState>>,
@@ -460,6 +695,21 @@ fn update_ui(
             }
         }
     }
+    if let Ok(mut soldier_target_mode_selector) = soldier_target_mode_selector.single_mut() {
+        if let Some(changed_item) = soldier_target_mode_selector.get_changed_item() {
+            for (_soldier_entity, mut soldier, soldier_tile_position) in soldiers.iter_mut() {
+                if soldier_tile_position.as_vec2() != selected_soldier.tile_position.as_vec2() {
+                    continue;
+                }
+
+                soldier.set_target_mode(SoldierTargetMode::from_index(
+                    changed_item.value.as_f32() as usize
+                ));
+
+                break;
+            }
+        }
+    }
     for (ui_button_interaction, button_action) in interaction_query.iter() {
         if *ui_button_interaction != UiButtonInteraction::Clicked {
             continue;
@@ -469,21 +719,21 @@ fn update_ui(
                 next_ui_state.set(UiState::InGame);
                 next_game_state.set(GameState::InGame);
             }
-            ButtonAction::UpgradeSoldier => {
+            ButtonAction::UpgradeSoldier(level) => {
                 for (_soldier_entity, mut soldier, soldier_tile_position) in soldiers.iter_mut() {
                     if soldier_tile_position.as_vec2() != selected_soldier.tile_position.as_vec2() {
                         continue;
                     }
 
-                    let next_level_price = soldier.get_next_level_config().get_price();
+                    let next_level_price = soldier.with_level(*level).get_config().get_price();
 
-                    if soldier.is_next_level_allowed() == false
+                    if soldier.is_upgrade_allowed(*level) == false
                         || player.get_money().get_current() < next_level_price
                     {
                         break;
                     }
 
-                    soldier.get_variant_mut().set_next_level();
+                    soldier.get_variant_mut().set_upgrade(*level);
                     player.get_money_mut().decrease(next_level_price);
 
                     selected_tile
@@ -505,7 +755,7 @@ fn update_ui(
                     commands.entity(soldier_entity).despawn();
                     player
                         .get_money_mut()
-                        .increase(soldier.get_config().get_sell_price());
+                        .increase(selected_level.get_sell_price(soldier.get_sell_price()));
 
                     break;
                 }
@@ -521,102 +771,195 @@ fn update_ui(
 }
 
 fn update_soldier_info(
-    interaction_query: Query<
-        (&UiButtonInteraction, &ButtonAction),
-        (Changed<UiButtonInteraction>, With<UiButton>),
-    >,
+    changed_interaction_query: Query<&ButtonAction, (Changed<UiButtonInteraction>, With<UiButton>)>,
+    interaction_query: Query<(&UiButtonInteraction, &ButtonAction), With<UiButton>>,
     mut soldier_info_components: Query<(&mut TextColor, &mut I18nComponent, &SoldierInfoComponent)>,
     soldiers: Query<(&Soldier, &TilePosition)>,
     selected_soldier: Res<SelectedSoldier>,
 ) {
-    for (ui_button_interaction, button_action) in interaction_query.iter() {
-        if *button_action != ButtonAction::UpgradeSoldier {
-            continue;
-        }
-
-        for (soldier, soldier_tile_position) in soldiers.iter() {
-            if soldier_tile_position.as_vec2() != selected_soldier.tile_position.as_vec2() {
-                continue;
-            }
-
-            let current_config = soldier.get_config();
-            let next_level_config = soldier.get_next_level_config();
-
-            if soldier.is_next_level_allowed() == false {
-                break;
-            }
+    if changed_interaction_query
+        .iter()
+        .any(|button_action| matches!(button_action, ButtonAction::UpgradeSoldier(_)))
+        == false
+    {
+        return;
+    }
 
-            let show_next_level = matches!(
-                *ui_button_interaction,
-                UiButtonInteraction::Hovered | UiButtonInteraction::Clicked
+    let hovered_level =
+        interaction_query
+            .iter()
+            .find_map(
+                |(ui_button_interaction, button_action)| match button_action {
+                    ButtonAction::UpgradeSoldier(level)
+                        if matches!(
+                            *ui_button_interaction,
+                            UiButtonInteraction::Hovered | UiButtonInteraction::Clicked
+                        ) =>
+                    {
+                        Some(*level)
+                    }
+                    _ => None,
+                },
             );
 
-            let dispayed_config = if show_next_level {
-                next_level_config
-            } else {
-                current_config
-            };
+    for (soldier, soldier_tile_position) in soldiers.iter() {
+        if soldier_tile_position.as_vec2() != selected_soldier.tile_position.as_vec2() {
+            continue;
+        }
 
-            for (
-                mut soldier_info_component_text_color,
-                mut soldier_info_i18n_component,
-                soldier_info_component,
-            ) in soldier_info_components.iter_mut()
-            {
-                let (key, value, value_changed) = match soldier_info_component {
-                    SoldierInfoComponent::Level => (
-                        "level",
-                        if show_next_level {
-                            soldier.get_next_level().saturating_add(1).to_string()
-                        } else {
-                            soldier.get_level().saturating_add(1).to_string()
-                        },
-                        true,
-                    ),
-                    SoldierInfoComponent::Damage => (
-                        "damage",
-                        dispayed_config.get_damage().to_string(),
-                        current_config.get_damage() != next_level_config.get_damage(),
+        let current_config = soldier.get_config();
+        let next_variant = hovered_level
+            .filter(|level| soldier.is_upgrade_allowed(*level))
+            .map_or(soldier.get_variant(), |level| soldier.with_level(level));
+        let next_level_config = next_variant.get_config();
+
+        let show_next_level = next_variant != soldier.get_variant();
+
+        let bonus = soldier.get_bonus();
+
+        let dispayed_config = if show_next_level {
+            next_level_config
+        } else {
+            current_config
+        };
+
+        for (
+            mut soldier_info_component_text_color,
+            mut soldier_info_i18n_component,
+            soldier_info_component,
+        ) in soldier_info_components.iter_mut()
+        {
+            let (key, value, value_changed) = match soldier_info_component {
+                SoldierInfoComponent::Level => (
+                    "level",
+                    next_variant.get_tier().saturating_add(1).to_string(),
+                    true,
+                ),
+                SoldierInfoComponent::Damage => (
+                    "damage",
+                    format_buffed_value(
+                        dispayed_config.get_damage() as f32,
+                        bonus.apply_damage(dispayed_config.get_damage()) as f32,
                     ),
-                    SoldierInfoComponent::FireRadius => (
-                        "fire_radius",
-                        dispayed_config.get_fire_radius().to_string(),
-                        current_config.get_fire_radius() != next_level_config.get_fire_radius(),
+                    current_config.get_damage() != next_level_config.get_damage(),
+                ),
+                SoldierInfoComponent::FireRadius => (
+                    "fire_radius",
+                    format_buffed_value(
+                        dispayed_config.get_fire_radius(),
+                        bonus.apply_fire_radius(dispayed_config.get_fire_radius()),
                     ),
-                    SoldierInfoComponent::BlastRadius => {
-                        if let Some(blast_radius) =
-                            dispayed_config.get_projectile_variant().get_blast_radius()
-                        {
-                            (
-                                "blast_radius",
-                                blast_radius.to_string(),
-                                current_config.get_projectile_variant().get_blast_radius()
-                                    != next_level_config
-                                        .get_projectile_variant()
-                                        .get_blast_radius(),
-                            )
-                        } else {
-                            continue;
-                        }
+                    current_config.get_fire_radius() != next_level_config.get_fire_radius(),
+                ),
+                SoldierInfoComponent::BlastRadius => {
+                    if let Some(blast_radius) = dispayed_config.get_blast_radius() {
+                        (
+                            "blast_radius",
+                            blast_radius.to_string(),
+                            current_config.get_blast_radius()
+                                != next_level_config.get_blast_radius(),
+                        )
+                    } else {
+                        continue;
                     }
-                    SoldierInfoComponent::FireRate => (
-                        "fire_rate",
-                        ((1.0 / dispayed_config.get_fire_rate().as_secs_f32() * 100.0).round()
-                            / 100.0)
-                            .to_string(),
-                        current_config.get_fire_rate() != next_level_config.get_fire_rate(),
+                }
+                SoldierInfoComponent::FireRate => (
+                    "fire_rate",
+                    format_buffed_value(
+                        get_shots_per_second(dispayed_config.get_fire_rate()),
+                        get_shots_per_second(
+                            bonus.apply_fire_rate(dispayed_config.get_fire_rate()),
+                        ),
                     ),
-                };
-
-                soldier_info_i18n_component.change_i18n_arg(key, value);
-                soldier_info_component_text_color.0 = if show_next_level && value_changed {
-                    Color::srgb(0.5, 1.0, 0.0)
-                } else {
-                    Color::WHITE
-                };
-            }
+                    current_config.get_fire_rate() != next_level_config.get_fire_rate(),
+                ),
+                SoldierInfoComponent::Charges => {
+                    let get_remaining_charges = |config: &SoldierConfig| {
+                        config
+                            .get_attack()
+                            .get_trap_charges()
+                            .map(|charges| charges.saturating_sub(soldier.get_used_charges()))
+                    };
+                    if let Some(remaining_charges) = get_remaining_charges(dispayed_config) {
+                        (
+                            "charges",
+                            remaining_charges.to_string(),
+                            get_remaining_charges(current_config)
+                                != get_remaining_charges(next_level_config),
+                        )
+                    } else {
+                        continue;
+                    }
+                }
+                SoldierInfoComponent::TargetDomains => (
+                    "domains",
+                    dispayed_config
+                        .get_target_domains()
+                        .iter()
+                        .map(|domain| rust_i18n::t!(domain.to_str()).to_string())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    current_config.get_target_domains() != next_level_config.get_target_domains(),
+                ),
+                SoldierInfoComponent::AuraDamage
+                | SoldierInfoComponent::AuraFireRate
+                | SoldierInfoComponent::AuraFireRadius => {
+                    let get_bonus = |config: &SoldierConfig| {
+                        config
+                            .get_attack()
+                            .get_aura()
+                            .map(|aura| match soldier_info_component {
+                                SoldierInfoComponent::AuraDamage => aura.get_damage(),
+                                SoldierInfoComponent::AuraFireRate => aura.get_fire_rate(),
+                                _ => aura.get_fire_radius(),
+                            })
+                    };
+                    if let Some(bonus) = get_bonus(dispayed_config) {
+                        (
+                            "bonus",
+                            format_aura_bonus(bonus),
+                            get_bonus(current_config) != get_bonus(next_level_config),
+                        )
+                    } else {
+                        continue;
+                    }
+                }
+            };
 
-            break;
+            soldier_info_i18n_component.change_i18n_arg(key, value);
+            soldier_info_component_text_color.0 = if show_next_level && value_changed {
+                Color::srgb(0.5, 1.0, 0.0)
+            } else {
+                Color::WHITE
+            };
         }
+
+        break;
+    }
+}
+
+fn get_shots_per_second(fire_rate: Duration) -> f32 {
+    (1.0 / fire_rate.as_secs_f32() * 100.0).round() / 100.0
+}
+
+fn format_buffed_value(base_value: f32, buffed_value: f32) -> String {
+    let bonus = ((buffed_value - base_value) * 100.0).round() / 100.0;
+
+    if bonus > 0.0 {
+        format!("{} (+{})", base_value, bonus)
+    } else {
+        base_value.to_string()
     }
 }
+
+fn format_rank_progress(value: u32, threshold: Option<u32>) -> String {
+    if let Some(threshold) = threshold {
+        format!("{}/{}", value, threshold)
+    } else {
+        value.to_string()
+    }
+}
+
+fn format_aura_bonus(bonus: f32) -> String {
+    (bonus * 100.0).round().to_string()
+}
@@ -122,15 +122,19 @@ fn init_ui(
                         .absolute(),
                 )
                 .with_child(
-                    UiText::new("ui.in_game.wave")
-                        .with_i18n_arg(
-                            "current",
-                            game_waves.get_current().saturating_add(1).to_string(),
-                        )
-                        .with_i18n_arg(
-                            "total",
-                            game_waves.get_total().saturating_add(1).to_string(),
-                        ),
+                    UiText::new(if game_waves.is_endless() == true {
+                        "ui.in_game.endless_wave"
+                    } else {
+                        "ui.in_game.wave"
+                    })
+                    .with_i18n_arg(
+                        "current",
+                        game_waves.get_current().saturating_add(1).to_string(),
+                    )
+                    .with_i18n_arg(
+                        "total",
+                        game_waves.get_total().saturating_add(1).to_string(),
+                    ),
                 );
 
             parent
@@ -55,6 +55,7 @@ fn init_ui(
     game_config: Res<Persistent<GameConfig>>,
     player: Res<Player>,
     game_waves: Res<GameWaves>,
+    selected_soldier: Res<SelectedSoldier>,
 ) {
     commands
         .spawn((
@@ -137,21 +138,25 @@ fn init_ui(
                         .absolute(),
                 )
                 .with_child(
-                    UiText::new("ui.in_game.wave")
-                        .with_i18n_arg(
-                            "current",
-                            game_waves.get_current().saturating_add(1).to_string(),
-                        )
-                        .with_i18n_arg(
-                            "total",
-                            game_waves.get_total().saturating_add(1).to_string(),
-                        ),
+                    UiText::new(if game_waves.is_endless() == true {
+                        "ui.in_game.endless_wave"
+                    } else {
+                        "ui.in_game.wave"
+                    })
+                    .with_i18n_arg(
+                        "current",
+                        game_waves.get_current().saturating_add(1).to_string(),
+                    )
+                    .with_i18n_arg(
+                        "total",
+                        game_waves.get_total().saturating_add(1).to_string(),
+                    ),
                 );
 
             parent
                 .spawn(
                     UiContainer::primary()
-                        .with_width(Val::Px(380.0))
+                        .with_width(Val::Px(600.0))
                         .with_padding(UiRect::all(Val::Px(24.0)))
                         .with_row_gap(Val::Px(12.0))
                         .center()
@@ -181,13 +186,23 @@ fn init_ui(
                         .with_child(UiText::new("ui.soldier_select.title"));
 
                     parent
-                        .spawn(UiContainer::new().with_row_gap(Val::Px(8.0)).column())
+                        .spawn(Node {
+                            display: Display::Grid,
+                            width: Val::Percent(100.0),
+                            grid_template_columns: RepeatedGridTrack::flex(2, 1.0),
+                            row_gap: Val::Px(8.0),
+                            column_gap: Val::Px(8.0),
+                            ..default()
+                        })
                         .with_children(|parent| {
-                            for soldier_variant in [
-                                SoldierVariant::Soldier { level: 0 },
-                                SoldierVariant::RocketLauncher { level: 0 },
-                                SoldierVariant::Sniper { level: 0 },
-                            ] {
+                            for soldier_variant in
+                                SoldierVariant::get_all()
+                                    .into_iter()
+                                    .filter(|soldier_variant| {
+                                        soldier_variant
+                                            .can_be_placed_on(selected_soldier.tile_variant)
+                                    })
+                            {
                                 parent
                                     .spawn((
                                         ButtonAction::Select(soldier_variant),
@@ -291,7 +306,7 @@ fn init_ui(
                                                         .with_i18n_arg(
                                                             "max_level",
                                                             soldier_variant
-                                                                .get_max_level()
+                                                                .get_max_tier()
                                                                 .saturating_add(1)
                                                                 .to_string(),
                                                         )
@@ -299,65 +314,155 @@ fn init_ui(
                                                         .with_justify(Justify::Left),
                                                 );
 
-                                                parent.spawn(
-                                                    UiText::new("soldier.info.damage")
-                                                        .with_i18n_arg(
-                                                            "damage",
-                                                            soldier_variant
-                                                                .get_config()
-                                                                .get_damage()
-                                                                .to_string(),
-                                                        )
-                                                        .with_size(UiTextSize::Small)
-                                                        .with_justify(Justify::Left),
-                                                );
-
-                                                parent.spawn(
-                                                    UiText::new("soldier.info.fire_radius")
-                                                        .with_i18n_arg(
-                                                            "fire_radius",
-                                                            soldier_variant
-                                                                .get_config()
-                                                                .get_fire_radius()
-                                                                .to_string(),
-                                                        )
-                                                        .with_size(UiTextSize::Small)
-                                                        .with_justify(Justify::Left),
-                                                );
-
-                                                if let Some(blast_radius) = soldier_variant
+                                                if let Some(aura) = soldier_variant
                                                     .get_config()
-                                                    .get_projectile_variant()
-                                                    .get_blast_radius()
+                                                    .get_attack()
+                                                    .get_aura()
                                                 {
                                                     parent.spawn(
-                                                        UiText::new("soldier.info.blast_radius")
+                                                        UiText::new("soldier.info.aura_radius")
+                                                            .with_i18n_arg(
+                                                                "fire_radius",
+                                                                soldier_variant
+                                                                    .get_config()
+                                                                    .get_fire_radius()
+                                                                    .to_string(),
+                                                            )
+                                                            .with_size(UiTextSize::Small)
+                                                            .with_justify(Justify::Left),
+                                                    );
+
+                                                    for (key, bonus) in [
+                                                        (
+                                                            "soldier.info.aura_damage",
+                                                            aura.get_damage(),
+                                                        ),
+                                                        (
+                                                            "soldier.info.aura_fire_rate",
+                                                            aura.get_fire_rate(),
+                                                        ),
+                                                        (
+                                                            "soldier.info.aura_fire_radius",
+                                                            aura.get_fire_radius(),
+                                                        ),
+                                                    ] {
+                                                        if bonus <= 0.0 {
+                                                            continue;
+                                                        }
+                                                        parent.spawn(
+                                                            UiText::new(key)
+                                                                .with_i18n_arg(
+                                                                    "bonus",
+                                                                    (bonus * 100.0)
+                                                                        .round()
+                                                                        .to_string(),
+                                                                )
+                                                                .with_size(UiTextSize::Small)
+                                                                .with_justify(Justify::Left),
+                                                        );
+                                                    }
+                                                } else {
+                                                    let trap_charges = soldier_variant
+                                                        .get_config()
+                                                        .get_attack()
+                                                        .get_trap_charges();
+
+                                                    parent.spawn(
+                                                        UiText::new("soldier.info.damage")
+                                                            .with_i18n_arg(
+                                                                "damage",
+                                                                soldier_variant
+                                                                    .get_config()
+                                                                    .get_damage()
+                                                                    .to_string(),
+                                                            )
+                                                            .with_size(UiTextSize::Small)
+                                                            .with_justify(Justify::Left),
+                                                    );
+
+                                                    if trap_charges.is_none() {
+                                                        parent.spawn(
+                                                            UiText::new("soldier.info.fire_radius")
+                                                                .with_i18n_arg(
+                                                                    "fire_radius",
+                                                                    soldier_variant
+                                                                        .get_config()
+                                                                        .get_fire_radius()
+                                                                        .to_string(),
+                                                                )
+                                                                .with_size(UiTextSize::Small)
+                                                                .with_justify(Justify::Left),
+                                                        );
+                                                    }
+
+                                                    if let Some(blast_radius) = soldier_variant
+                                                        .get_config()
+                                                        .get_blast_radius()
+                                                    {
+                                                        parent.spawn(
+                                                            UiText::new(
+                                                                "soldier.info.blast_radius",
+                                                            )
                                                             .with_i18n_arg(
                                                                 "blast_radius",
                                                                 blast_radius.to_string(),
                                                             )
                                                             .with_size(UiTextSize::Small)
                                                             .with_justify(Justify::Left),
-                                                    );
-                                                }
+                                                        );
+                                                    }
 
-                                                parent.spawn(
-                                                    UiText::new("soldier.info.fire_rate")
-                                                        .with_i18n_arg(
-                                                            "fire_rate",
-                                                            ((1.0
-                                                                / soldier_variant
+                                                    if let Some(charges) = trap_charges {
+                                                        parent.spawn(
+                                                            UiText::new("soldier.info.charges")
+                                                                .with_i18n_arg(
+                                                                    "charges",
+                                                                    charges.to_string(),
+                                                                )
+                                                                .with_size(UiTextSize::Small)
+                                                                .with_justify(Justify::Left),
+                                                        );
+                                                    } else {
+                                                        parent.spawn(
+                                                            UiText::new("soldier.info.fire_rate")
+                                                                .with_i18n_arg(
+                                                                    "fire_rate",
+                                                                    ((1.0
+                                                                        / soldier_variant
+                                                                            .get_config()
+                                                                            .get_fire_rate()
+                                                                            .as_secs_f32()
+                                                                        * 100.0)
+                                                                        .round()
+                                                                        / 100.0)
+                                                                        .to_string(),
+                                                                )
+                                                                .with_size(UiTextSize::Small)
+                                                                .with_justify(Justify::Left),
+                                                        );
+                                                    }
+
+                                                    parent.spawn(
+                                                        UiText::new("soldier.info.target_domains")
+                                                            .with_i18n_arg(
+                                                                "domains",
+                                                                soldier_variant
                                                                     .get_config()
-                                                                    .get_fire_rate()
-                                                                    .as_secs_f32()
-                                                                * 100.0)
-                                                                .round()
-                                                                / 100.0)
-                                                                .to_string(),
-                                                        )
-                                                        .with_size(UiTextSize::Small)
-                                                        .with_justify(Justify::Left),
-                                                );
+                                                                    .get_target_domains()
+                                                                    .iter()
+                                                                    .map(|domain| {
+                                                                        rust_i18n::t!(
+                                                                            domain.to_str()
+                                                                        )
+                                                                        .to_string()
+                                                                    })
+                                                                    .collect::<Vec<_>>()
+                                                                    .join(", "),
+                                                            )
+                                                            .with_size(UiTextSize::Small)
+                                                            .with_justify(Justify::Left),
+                                                    );
+                                                }
                                             });
                                     });
                             }
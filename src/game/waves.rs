@@ -4,8 +4,11 @@ use bevy::prelude::*;
 use bevy_persistent::Persistent;
 
 use crate::game::{
-    assets::levels::{CompletedLevels, Level, LevelCompletionStars},
-    entities::{enemy::Enemy, tile::movement::TileMovement},
+    assets::levels::{CompletedLevels, EndlessRecords, Level, LevelCompletionStars},
+    entities::{
+        enemy::{Enemy, health::EnemyHealth, route::EnemyRoutes},
+        tile::movement::TileMovement,
+    },
     player::Player,
     ui::UiState,
     {GameState, GameTilemap},
@@ -24,6 +27,10 @@ pub struct GameWaves {
     total: usize,
     current: usize,
     state: WaveState,
+    in_progress_waves: Vec<usize>,
+    overlapping_allowed: bool,
+    endless: bool,
+    early_called: bool,
 }
 
 impl Default for GameWaves {
@@ -32,15 +39,23 @@ impl Default for GameWaves {
             total: 0,
             current: 0,
             state: WaveState::NotStarted,
+            in_progress_waves: Vec::new(),
+            overlapping_allowed: false,
+            endless: false,
+            early_called: false,
         }
     }
 }
 
 impl GameWaves {
-    pub fn restart(&mut self, total: usize) {
+    pub fn restart(&mut self, total: usize, overlapping_allowed: bool, endless: bool) {
         self.total = total;
         self.current = 0;
         self.state = WaveState::NotStarted;
+        self.in_progress_waves.clear();
+        self.overlapping_allowed = overlapping_allowed;
+        self.endless = endless;
+        self.early_called = false;
     }
     pub fn get_total(&self) -> usize {
         self.total
@@ -48,29 +63,52 @@ impl GameWaves {
     pub fn get_current(&self) -> usize {
         self.current
     }
+    pub fn is_endless(&self) -> bool {
+        self.endless
+    }
     pub fn get_state(&self) -> WaveState {
         self.state
     }
     pub fn set_state(&mut self, state: WaveState) {
         self.state = state;
     }
+    pub fn get_in_progress_waves(&self) -> &Vec<usize> {
+        &self.in_progress_waves
+    }
+    pub fn add_in_progress_wave(&mut self, wave: usize) {
+        self.in_progress_waves.push(wave);
+    }
+    pub fn remove_in_progress_wave(&mut self, wave: usize) {
+        self.in_progress_waves
+            .retain(|in_progress_wave| *in_progress_wave != wave);
+    }
     pub fn next_wave(&mut self) {
         if self.state == WaveState::NotStarted {
             self.state = WaveState::Setup;
             return;
         }
-        if self.current >= self.total {
+        if self.is_last() == true {
             return;
         }
-        self.current = self.current.saturating_add(1).min(self.total);
+        self.early_called = self.state == WaveState::InProgress;
+        self.current = self.current.saturating_add(1);
         self.state = WaveState::Setup;
     }
     pub fn is_last(&self) -> bool {
-        self.current == self.total
+        self.endless == false && self.current >= self.total
     }
     pub fn is_next_wave_allowed(&self) -> bool {
         self.state == WaveState::NotStarted
             || self.state == WaveState::Completed && self.is_last() == false
+            || self.is_overlapping_allowed() == true
+    }
+    pub fn is_overlapping_allowed(&self) -> bool {
+        self.overlapping_allowed == true
+            && self.state == WaveState::InProgress
+            && self.is_last() == false
+    }
+    pub fn is_early_called(&self) -> bool {
+        self.early_called
     }
     pub fn is_fully_completed(&self) -> bool {
         self.state == WaveState::Completed && self.is_last() == true
@@ -97,10 +135,12 @@ impl Plugin for GameWavesPlugin {
 fn update_wave(
     mut commands: Commands,
     game_tilemap: Single<Entity, With<GameTilemap>>,
+    enemies: Query<(&EnemyHealth, &TileMovement), With<Enemy>>,
     selected_level: Res<Level>,
-    mut completed_levels: ResMut<Persistent<CompletedLevels>>,
+    enemy_routes: Res<EnemyRoutes>,
+    mut endless_records: ResMut<Persistent<EndlessRecords>>,
     mut game_waves: ResMut<GameWaves>,
-    player: Res<Player>,
+    mut player: ResMut<Player>,
     mut next_ui_state: ResMut<NextState<UiState>>,
     mut next_game_state: ResMut<NextState<GameState>>,
 ) {
@@ -114,14 +154,6 @@ fn update_wave(
         if game_waves.is_fully_completed() == true {
             next_ui_state.set(UiState::GameOver);
             next_game_state.set(GameState::Pause);
-            completed_levels
-                .update(|levels| {
-                    levels.add(
-                        &selected_level.get_name(),
-                        LevelCompletionStars::from_player_health(player.get_health()),
-                    )
-                })
-                .unwrap();
         }
         return;
     }
@@ -129,38 +161,120 @@ fn update_wave(
         return;
     };
 
+    if game_waves.is_endless() == true {
+        let reached_wave = game_waves.get_current().saturating_add(1);
+        if endless_records
+            .get_best_wave(selected_level.get_name(), selected_level.get_difficulty())
+            .is_none_or(|best_wave| best_wave < reached_wave)
+        {
+            endless_records
+                .update(|records| {
+                    records.add(
+                        selected_level.get_name(),
+                        selected_level.get_difficulty(),
+                        reached_wave,
+                    )
+                })
+                .unwrap();
+        }
+    }
+
+    if game_waves.is_early_called() == true {
+        player
+            .get_money_mut()
+            .increase(selected_level.get_early_call_bonus(get_remaining_health(&enemies)));
+    }
+
     for wave_enemies in wave.get_enemies().iter() {
         for index in 0..wave_enemies.get_count() {
-            commands.entity(game_tilemap.entity()).with_child((
-                Enemy::new(wave_enemies.get_enemy_variant()),
-                TileMovement::new(
-                    selected_level
-                        .get_path(wave_enemies.get_path_index())
-                        .get_points()
-                        .clone(),
-                    Duration::from_secs_f32(wave_enemies.get_duration()),
-                    Some(Duration::from_secs_f32(
-                        wave_enemies.get_spawn_interval() * index as f32
-                            + wave_enemies.get_spawn_delay(),
-                    )),
+            let enemy = Enemy::new(wave_enemies.get_enemy_variant())
+                .with_layered(wave_enemies.is_layered())
+                .with_boss(wave_enemies.is_boss())
+                .with_wave(game_waves.get_current())
+                .with_health_multiplier(wave_enemies.get_health_multiplier())
+                .with_duration_override(wave_enemies.get_duration().is_some());
+            let enemy_path = enemy_routes
+                .get_path(
+                    &selected_level,
+                    wave_enemies.get_path_index(),
+                    enemy.get_domain(),
+                )
+                .get_points()
+                .clone();
+            let enemy_delay = Some(Duration::from_secs_f32(
+                wave_enemies.get_spawn_interval() * index as f32 + wave_enemies.get_spawn_delay(),
+            ));
+            let enemy_movement = match wave_enemies.get_duration() {
+                Some(duration) => {
+                    TileMovement::new(enemy_path, Duration::from_secs_f32(duration), enemy_delay)
+                }
+                None => TileMovement::from_speed(
+                    enemy_path,
+                    enemy.get_config().get_speed(),
+                    enemy_delay,
                 ),
+            };
+
+            commands.entity(game_tilemap.entity()).with_child((
+                enemy,
+                enemy_movement,
                 Transform::from_scale(Vec3::ZERO),
             ));
         }
     }
+    let current_wave = game_waves.get_current();
+    game_waves.add_in_progress_wave(current_wave);
     game_waves.set_state(WaveState::InProgress);
 }
 
 fn update_wave_state(
     enemies: Query<&Enemy>,
     selected_level: Res<Level>,
+    mut completed_levels: ResMut<Persistent<CompletedLevels>>,
     mut game_waves: ResMut<GameWaves>,
     mut player: ResMut<Player>,
 ) {
-    if enemies.is_empty() && game_waves.get_state() == WaveState::InProgress {
-        game_waves.set_state(WaveState::Completed);
-        if let Some(wave) = selected_level.get_wave(game_waves.get_current()) {
+    if game_waves.get_state() != WaveState::InProgress {
+        return;
+    }
+
+    let cleared_waves = game_waves
+        .get_in_progress_waves()
+        .iter()
+        .copied()
+        .filter(|wave_index| enemies.iter().any(|enemy| enemy.get_wave() == *wave_index) == false)
+        .collect::<Vec<_>>();
+
+    for wave_index in cleared_waves {
+        game_waves.remove_in_progress_wave(wave_index);
+        if let Some(wave) = selected_level.get_wave(wave_index) {
             player.get_money_mut().increase(wave.get_reward());
         }
     }
+
+    if game_waves.get_in_progress_waves().is_empty() {
+        let interest = selected_level.get_interest(player.get_money().get_current());
+        player.get_money_mut().increase(interest);
+        game_waves.set_state(WaveState::Completed);
+
+        if game_waves.is_fully_completed() == true {
+            completed_levels
+                .update(|levels| {
+                    levels.add(
+                        &selected_level.get_name(),
+                        selected_level.get_difficulty(),
+                        LevelCompletionStars::from_player_health(player.get_health()),
+                    )
+                })
+                .unwrap();
+        }
+    }
+}
+
+pub fn get_remaining_health(enemies: &Query<(&EnemyHealth, &TileMovement), With<Enemy>>) -> u32 {
+    enemies
+        .iter()
+        .filter(|(_, enemy_movement)| enemy_movement.get_progress() > 0.0)
+        .map(|(enemy_health, _)| enemy_health.get_current())
+        .sum()
 }